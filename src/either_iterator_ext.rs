@@ -0,0 +1,165 @@
+//! The trait [`EitherIteratorExt`] provides methods for iterators whose items
+//! are [`Either`] values.
+
+use super::{Either, Left, Right};
+use core::iter::{self, FromIterator};
+
+/// Extension methods for iterators over [`Either<L, R>`](Either) items.
+///
+/// This trait is implemented for every [`Iterator`] with `Either` items, so
+/// it only needs to be imported to be used.
+pub trait EitherIteratorExt<L, R>: Iterator<Item = Either<L, R>> + Sized {
+    /// Count the number of [`Left`] and [`Right`] items, returned in that order.
+    ///
+    /// This consumes the iterator in a single pass.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use either::{EitherIteratorExt, Left, Right};
+    ///
+    /// let values = [Left(1), Right("a"), Left(2), Left(3), Right("b")];
+    /// assert_eq!(values.iter().cloned().count_sides(), (3, 2));
+    /// ```
+    fn count_sides(self) -> (usize, usize) {
+        self.fold((0, 0), |(left, right), item| match item {
+            Left(_) => (left + 1, right),
+            Right(_) => (left, right + 1),
+        })
+    }
+
+    /// Return an iterator over the [`Left`] values, skipping [`Right`] values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use either::{EitherIteratorExt, Left, Right};
+    ///
+    /// let values = vec![Left(1), Right("a"), Left(2)];
+    /// assert_eq!(values.into_iter().left_values().collect::<Vec<_>>(), vec![1, 2]);
+    /// ```
+    #[allow(clippy::type_complexity)]
+    fn left_values(self) -> iter::FilterMap<Self, fn(Either<L, R>) -> Option<L>> {
+        self.filter_map(Either::left)
+    }
+
+    /// Return an iterator over the [`Right`] values, skipping [`Left`] values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use either::{EitherIteratorExt, Left, Right};
+    ///
+    /// let values = vec![Left(1), Right("a"), Left(2)];
+    /// assert_eq!(values.into_iter().right_values().collect::<Vec<_>>(), vec!["a"]);
+    /// ```
+    #[allow(clippy::type_complexity)]
+    fn right_values(self) -> iter::FilterMap<Self, fn(Either<L, R>) -> Option<R>> {
+        self.filter_map(Either::right)
+    }
+
+    /// Split the items into a collection of [`Left`] values and a collection
+    /// of [`Right`] values, returned in that order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use either::{EitherIteratorExt, Left, Right};
+    ///
+    /// let values = vec![Left(1), Right("a"), Left(2), Right("b")];
+    /// let (numbers, words): (Vec<_>, Vec<_>) = values.into_iter().partition_results();
+    /// assert_eq!(numbers, vec![1, 2]);
+    /// assert_eq!(words, vec!["a", "b"]);
+    /// ```
+    fn partition_results<A, B>(self) -> (A, B)
+    where
+        A: Default + Extend<L>,
+        B: Default + Extend<R>,
+    {
+        let mut left = A::default();
+        let mut right = B::default();
+        self.extend_both(&mut left, &mut right);
+        (left, right)
+    }
+
+    /// Extend `left` with the [`Left`] values and `right` with the [`Right`]
+    /// values.
+    ///
+    /// This is like [`partition_results`][EitherIteratorExt::partition_results],
+    /// but adds to existing collections.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use either::{EitherIteratorExt, Left, Right};
+    ///
+    /// let mut numbers = vec![0];
+    /// let mut words = vec!["zero"];
+    ///
+    /// let values = vec![Left(1), Right("one"), Left(2)];
+    /// values.into_iter().extend_both(&mut numbers, &mut words);
+    ///
+    /// assert_eq!(numbers, vec![0, 1, 2]);
+    /// assert_eq!(words, vec!["zero", "one"]);
+    /// ```
+    fn extend_both<A, B>(self, left: &mut A, right: &mut B)
+    where
+        A: Extend<L>,
+        B: Extend<R>,
+    {
+        self.for_each(|item| match item {
+            Left(l) => left.extend(Some(l)),
+            Right(r) => right.extend(Some(r)),
+        });
+    }
+
+    /// Collect the [`Left`] values, treating them like `Ok`, or stop at the
+    /// first [`Right`] value and return it as the error.
+    ///
+    /// This mirrors collecting an iterator of `Result` into a
+    /// `Result<C, E>`, with [`Left`] as the success channel.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use either::{Either, EitherIteratorExt, Left, Right};
+    ///
+    /// let values: Vec<Either<u32, &str>> = vec![Left(1), Left(2)];
+    /// let collected: Result<Vec<_>, _> = values.into_iter().collect_results();
+    /// assert_eq!(collected, Ok(vec![1, 2]));
+    ///
+    /// let mut values = vec![Left(1), Right("stop"), Left(2)].into_iter();
+    /// let collected: Result<Vec<_>, _> = values.by_ref().collect_results();
+    /// assert_eq!(collected, Err("stop"));
+    /// assert_eq!(values.next(), Some(Left(2)));
+    /// ```
+    fn collect_results<C>(self) -> Result<C, R>
+    where
+        C: FromIterator<L>,
+    {
+        self.map(Either::right_into_err).collect()
+    }
+
+    /// Build a `String` from the items, converting each side into a `char`.
+    ///
+    /// Requires crate feature `"use_std"`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use either::{EitherIteratorExt, Left, Right};
+    ///
+    /// let values = vec![Left('a'), Right(b'b'), Left('c')];
+    /// assert_eq!(values.into_iter().collect_string(), "abc");
+    /// ```
+    #[cfg(any(test, feature = "use_std"))]
+    fn collect_string(self) -> std::string::String
+    where
+        L: Into<char>,
+        R: Into<char>,
+    {
+        self.map(Either::either_into::<char>).collect()
+    }
+}
+
+impl<I, L, R> EitherIteratorExt<L, R> for I where I: Iterator<Item = Either<L, R>> {}
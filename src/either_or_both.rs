@@ -0,0 +1,132 @@
+//! The enum [`EitherOrBoth`] extends [`Either`] with a case holding both values.
+
+use super::Either;
+use core::convert::TryFrom;
+
+/// A value of type `L`, a value of type `R`, or both.
+///
+/// This is useful for merges where both sides can be present at once, like
+/// zipping two sequences of different lengths, which `Either` can't express.
+///
+/// ```
+/// use either::{Either, EitherOrBoth};
+///
+/// let value = EitherOrBoth::from(Either::<_, &str>::Left(1));
+/// let value = value.or_both(Either::Right("a"));
+/// assert_eq!(value, EitherOrBoth::Both(1, "a"));
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum EitherOrBoth<L, R> {
+    /// Only a value of type `L`.
+    Left(L),
+    /// Only a value of type `R`.
+    Right(R),
+    /// Both a value of type `L` and a value of type `R`.
+    Both(L, R),
+}
+
+impl<L, R> EitherOrBoth<L, R> {
+    /// Return the left value, if present.
+    ///
+    /// ```
+    /// use either::EitherOrBoth;
+    ///
+    /// assert_eq!(EitherOrBoth::<_, ()>::Left(1).left(), Some(1));
+    /// assert_eq!(EitherOrBoth::Both(1, 'a').left(), Some(1));
+    /// assert_eq!(EitherOrBoth::<u32, _>::Right('a').left(), None);
+    /// ```
+    pub fn left(self) -> Option<L> {
+        match self {
+            EitherOrBoth::Left(l) | EitherOrBoth::Both(l, _) => Some(l),
+            EitherOrBoth::Right(_) => None,
+        }
+    }
+
+    /// Return the right value, if present.
+    ///
+    /// ```
+    /// use either::EitherOrBoth;
+    ///
+    /// assert_eq!(EitherOrBoth::<u32, _>::Right('a').right(), Some('a'));
+    /// assert_eq!(EitherOrBoth::Both(1, 'a').right(), Some('a'));
+    /// assert_eq!(EitherOrBoth::<_, char>::Left(1).right(), None);
+    /// ```
+    pub fn right(self) -> Option<R> {
+        match self {
+            EitherOrBoth::Right(r) | EitherOrBoth::Both(_, r) => Some(r),
+            EitherOrBoth::Left(_) => None,
+        }
+    }
+
+    /// Return both values, if both are present.
+    ///
+    /// ```
+    /// use either::EitherOrBoth;
+    ///
+    /// assert_eq!(EitherOrBoth::Both(1, 'a').both(), Some((1, 'a')));
+    /// assert_eq!(EitherOrBoth::<_, char>::Left(1).both(), None);
+    /// ```
+    pub fn both(self) -> Option<(L, R)> {
+        match self {
+            EitherOrBoth::Both(l, r) => Some((l, r)),
+            _ => None,
+        }
+    }
+
+    /// Add the value of `other` on its side, if that side isn't present yet.
+    ///
+    /// If the side of `other` is already present, `self` is returned
+    /// unchanged and `other` is dropped.
+    ///
+    /// ```
+    /// use either::{EitherOrBoth, Left, Right};
+    ///
+    /// let left = EitherOrBoth::<_, char>::Left(1);
+    /// assert_eq!(left.or_both(Right('a')), EitherOrBoth::Both(1, 'a'));
+    /// assert_eq!(left.or_both(Left(2)), EitherOrBoth::Left(1));
+    ///
+    /// let both = EitherOrBoth::Both(1, 'a');
+    /// assert_eq!(both.or_both(Right('b')), EitherOrBoth::Both(1, 'a'));
+    /// ```
+    pub fn or_both(self, other: Either<L, R>) -> Self {
+        match (self, other) {
+            (EitherOrBoth::Left(l), Either::Right(r)) => EitherOrBoth::Both(l, r),
+            (EitherOrBoth::Right(r), Either::Left(l)) => EitherOrBoth::Both(l, r),
+            (this, _) => this,
+        }
+    }
+}
+
+/// Convert from `Either` to `EitherOrBoth`, on the same side.
+impl<L, R> From<Either<L, R>> for EitherOrBoth<L, R> {
+    fn from(either: Either<L, R>) -> Self {
+        match either {
+            Either::Left(l) => EitherOrBoth::Left(l),
+            Either::Right(r) => EitherOrBoth::Right(r),
+        }
+    }
+}
+
+/// Convert from `EitherOrBoth` to `Either`, on the same side.
+///
+/// This fails for `Both`, returning both values as the error.
+///
+/// ```
+/// use either::{Either, EitherOrBoth, Left};
+/// use std::convert::TryFrom;
+///
+/// assert_eq!(Either::try_from(EitherOrBoth::<_, char>::Left(1)), Ok(Left(1)));
+/// assert_eq!(Either::try_from(EitherOrBoth::Both(1, 'a')), Err((1, 'a')));
+/// ```
+impl<L, R> TryFrom<EitherOrBoth<L, R>> for Either<L, R> {
+    type Error = (L, R);
+
+    fn try_from(value: EitherOrBoth<L, R>) -> Result<Self, Self::Error> {
+        match value {
+            EitherOrBoth::Left(l) => Ok(Either::Left(l)),
+            EitherOrBoth::Right(r) => Ok(Either::Right(r)),
+            EitherOrBoth::Both(l, r) => Err((l, r)),
+        }
+    }
+}
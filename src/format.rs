@@ -0,0 +1,118 @@
+//! Formatting adapters for [`Either`].
+
+use super::{Either, Left, Right};
+use core::fmt;
+
+/// Adapter that formats an [`Either`] with `Display`, using a separate
+/// function for each side.
+///
+/// This struct is created by the [`Either::display_with`] method.
+pub struct DisplayEither<'a, L, R, F, G> {
+    inner: &'a Either<L, R>,
+    left: F,
+    right: G,
+}
+
+impl<'a, L, R, F, G> DisplayEither<'a, L, R, F, G> {
+    pub(crate) fn new(inner: &'a Either<L, R>, left: F, right: G) -> Self {
+        DisplayEither { inner, left, right }
+    }
+}
+
+impl<'a, L, R, F, G> fmt::Display for DisplayEither<'a, L, R, F, G>
+where
+    F: Fn(&L, &mut fmt::Formatter<'_>) -> fmt::Result,
+    G: Fn(&R, &mut fmt::Formatter<'_>) -> fmt::Result,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self.inner {
+            Left(ref inner) => (self.left)(inner, f),
+            Right(ref inner) => (self.right)(inner, f),
+        }
+    }
+}
+
+/// Adapter that formats an [`Either`] with `Debug`, showing the value only
+/// when it is `Left`.
+///
+/// This struct is created by the [`Either::debug_left`] method.
+pub struct DebugLeft<'a, L, R> {
+    inner: &'a Either<L, R>,
+}
+
+impl<'a, L, R> DebugLeft<'a, L, R> {
+    pub(crate) fn new(inner: &'a Either<L, R>) -> Self {
+        DebugLeft { inner }
+    }
+}
+
+impl<'a, L, R> fmt::Debug for DebugLeft<'a, L, R>
+where
+    L: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self.inner {
+            Left(ref inner) => f.debug_tuple("Left").field(inner).finish(),
+            Right(_) => f.debug_tuple("Right").field(&Opaque).finish(),
+        }
+    }
+}
+
+/// Adapter that formats an [`Either`] with `Debug`, showing the value only
+/// when it is `Right`.
+///
+/// This struct is created by the [`Either::debug_right`] method.
+pub struct DebugRight<'a, L, R> {
+    inner: &'a Either<L, R>,
+}
+
+impl<'a, L, R> DebugRight<'a, L, R> {
+    pub(crate) fn new(inner: &'a Either<L, R>) -> Self {
+        DebugRight { inner }
+    }
+}
+
+impl<'a, L, R> fmt::Debug for DebugRight<'a, L, R>
+where
+    R: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self.inner {
+            Left(_) => f.debug_tuple("Left").field(&Opaque).finish(),
+            Right(ref inner) => f.debug_tuple("Right").field(inner).finish(),
+        }
+    }
+}
+
+/// Adapter that formats the value of an [`Either`] with `Debug`, without the
+/// `Left` or `Right` label.
+///
+/// This struct is created by the [`Either::debug_inner`] method.
+pub struct DebugInner<'a, L, R> {
+    inner: &'a Either<L, R>,
+}
+
+impl<'a, L, R> DebugInner<'a, L, R> {
+    pub(crate) fn new(inner: &'a Either<L, R>) -> Self {
+        DebugInner { inner }
+    }
+}
+
+impl<'a, L, R> fmt::Debug for DebugInner<'a, L, R>
+where
+    L: fmt::Debug,
+    R: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for_both!(*self.inner, ref inner => inner.fmt(f))
+    }
+}
+
+/// Placeholder for a value that can't be formatted.
+struct Opaque;
+
+impl fmt::Debug for Opaque {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("<opaque>")
+    }
+}
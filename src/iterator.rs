@@ -1,4 +1,4 @@
-use super::{for_both, Either, Left, Right};
+use super::{Either, Left, Right};
 use core::iter;
 
 macro_rules! wrap_either {
@@ -24,6 +24,290 @@ impl<L, R> IterEither<L, R> {
     pub(crate) fn new(inner: Either<L, R>) -> Self {
         IterEither { inner }
     }
+
+    /// Create an `IterEither` over the items of a `Left` iterator.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let iter: IterEither<_, std::vec::IntoIter<char>> = IterEither::from_left(1..3);
+    /// assert_eq!(iter.collect::<Vec<_>>(), vec![Left(1), Left(2)]);
+    /// ```
+    pub fn from_left(iter: L) -> Self {
+        IterEither::new(Left(iter))
+    }
+
+    /// Create an `IterEither` over the items of a `Right` iterator.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let iter: IterEither<std::ops::Range<u32>, _> = IterEither::from_right("ab".chars());
+    /// assert_eq!(iter.collect::<Vec<_>>(), vec![Right('a'), Right('b')]);
+    /// ```
+    pub fn from_right(iter: R) -> Self {
+        IterEither::new(Right(iter))
+    }
+
+    /// Unwrap the underlying `Left` or `Right` iterator.
+    ///
+    /// The iterator is returned in its current state, so any items that were
+    /// already consumed through the `IterEither` are not yielded again.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let value: Either<_, Vec<u8>> = Left(vec![1, 2, 3]);
+    /// let mut iter = value.factor_into_iter();
+    /// assert_eq!(iter.next(), Some(Left(1)));
+    ///
+    /// let rest: Vec<u32> = iter.by_arm().left().unwrap().collect();
+    /// assert_eq!(rest, vec![2, 3]);
+    /// ```
+    pub fn by_arm(self) -> Either<L, R> {
+        self.inner
+    }
+
+    /// Return an iterator over the unwrapped items of a `Left` iterator, or an
+    /// empty iterator if it is `Right`.
+    ///
+    /// A `Right` iterator is dropped without being iterated.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<_, Vec<char>> = Left(vec![1, 2]);
+    /// assert_eq!(left.factor_into_iter().left_only().collect::<Vec<_>>(), vec![1, 2]);
+    ///
+    /// let right: Either<Vec<u32>, _> = Right(vec!['a']);
+    /// assert_eq!(right.factor_into_iter().left_only().count(), 0);
+    /// ```
+    pub fn left_only(self) -> Either<L, iter::Empty<L::Item>>
+    where
+        L: Iterator,
+    {
+        match self.inner {
+            Left(inner) => Left(inner),
+            Right(_) => Right(iter::empty()),
+        }
+    }
+
+    /// Return an iterator over the unwrapped items of a `Right` iterator, or
+    /// an empty iterator if it is `Left`.
+    ///
+    /// A `Left` iterator is dropped without being iterated.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<_, Vec<char>> = Left(vec![1, 2]);
+    /// assert_eq!(left.factor_into_iter().right_only().count(), 0);
+    ///
+    /// let right: Either<Vec<u32>, _> = Right(vec!['a']);
+    /// assert_eq!(right.factor_into_iter().right_only().collect::<String>(), "a");
+    /// ```
+    pub fn right_only(self) -> Either<iter::Empty<R::Item>, R>
+    where
+        R: Iterator,
+    {
+        match self.inner {
+            Left(_) => Left(iter::empty()),
+            Right(inner) => Right(inner),
+        }
+    }
+
+    /// Extend `left` with all the items of a `Left` iterator, or `right` with
+    /// all the items of a `Right` iterator.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let mut numbers = vec![0];
+    /// let mut words = vec!["zero"];
+    ///
+    /// let left: Either<_, Vec<&str>> = Left(vec![1, 2]);
+    /// left.factor_into_iter().extend_both(&mut numbers, &mut words);
+    ///
+    /// let right: Either<Vec<u32>, _> = Right(vec!["one"]);
+    /// right.factor_into_iter().extend_both(&mut numbers, &mut words);
+    ///
+    /// assert_eq!(numbers, vec![0, 1, 2]);
+    /// assert_eq!(words, vec!["zero", "one"]);
+    /// ```
+    pub fn extend_both<A, B>(self, left: &mut A, right: &mut B)
+    where
+        L: Iterator,
+        R: Iterator,
+        A: Extend<L::Item>,
+        B: Extend<R::Item>,
+    {
+        match self.inner {
+            Left(inner) => left.extend(inner),
+            Right(inner) => right.extend(inner),
+        }
+    }
+
+    /// Creates an iterator which clones the values of an iterator over
+    /// `Either<&A, &B>` items, as in [`Iterator::cloned`].
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<_, Vec<u8>> = Left(vec![String::from("hello")]);
+    /// let owned: Vec<Either<String, u8>> = left.factor_iter().cloned().collect();
+    /// assert_eq!(owned, vec![Left(String::from("hello"))]);
+    /// ```
+    pub fn cloned<'a, A, B>(self) -> IterEither<iter::Cloned<L>, iter::Cloned<R>>
+    where
+        L: Iterator<Item = &'a A>,
+        R: Iterator<Item = &'a B>,
+        A: 'a + Clone,
+        B: 'a + Clone,
+    {
+        IterEither::new(map_either!(self.inner, inner => inner.cloned()))
+    }
+
+    /// Creates an iterator which copies the values of an iterator over
+    /// `Either<&A, &B>` items, as in [`Iterator::copied`].
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<[u32; 2], Vec<i64>> = Left([1, 2]);
+    /// let values: Vec<Either<u32, i64>> = left.factor_iter().copied().collect();
+    /// assert_eq!(values, vec![Left(1), Left(2)]);
+    ///
+    /// let right: Either<[u32; 2], Vec<i64>> = Right(vec![-3]);
+    /// let values: Vec<Either<u32, i64>> = right.factor_iter().copied().collect();
+    /// assert_eq!(values, vec![Right(-3)]);
+    /// ```
+    pub fn copied<'a, A, B>(self) -> IterEither<iter::Copied<L>, iter::Copied<R>>
+    where
+        L: Iterator<Item = &'a A>,
+        R: Iterator<Item = &'a B>,
+        A: 'a + Copy,
+        B: 'a + Copy,
+    {
+        IterEither::new(map_either!(self.inner, inner => inner.copied()))
+    }
+
+    /// Sum the items of the `Left` or `Right` iterator, keeping the sum on the
+    /// same side.
+    ///
+    /// Each side can be summed into a different type.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<_, Vec<f64>> = Left(1..5);
+    /// let sum: Either<i32, f64> = left.factor_into_iter().sum_either();
+    /// assert_eq!(sum, Left(10));
+    ///
+    /// let right: Either<std::ops::Range<i32>, _> = Right(vec![0.5, 1.5]);
+    /// let sum: Either<i32, f64> = right.factor_into_iter().sum_either();
+    /// assert_eq!(sum, Right(2.0));
+    /// ```
+    pub fn sum_either<A, B>(self) -> Either<A, B>
+    where
+        L: Iterator,
+        R: Iterator,
+        A: iter::Sum<L::Item>,
+        B: iter::Sum<R::Item>,
+    {
+        map_either!(self.inner, inner => inner.sum())
+    }
+
+    /// Creates an iterator that threads a state through the items of the
+    /// `Left` or `Right` iterator, as in [`Iterator::scan`], keeping each
+    /// result on the same side.
+    ///
+    /// Only the closure for the present side is used.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<_, Vec<f64>> = Left(vec![1, 2, 3]);
+    /// let sums: Vec<_> = left
+    ///     .factor_into_iter()
+    ///     .scan_arm(
+    ///         0.0,
+    ///         |sum, x| {
+    ///             *sum += f64::from(x);
+    ///             Some(*sum)
+    ///         },
+    ///         |sum, x| {
+    ///             *sum += x;
+    ///             Some(*sum)
+    ///         },
+    ///     )
+    ///     .collect();
+    /// assert_eq!(sums, vec![Left(1.0), Left(3.0), Left(6.0)]);
+    /// ```
+    pub fn scan_arm<St, F, G, A, B>(
+        self,
+        initial_state: St,
+        f: F,
+        g: G,
+    ) -> IterEither<iter::Scan<L, St, F>, iter::Scan<R, St, G>>
+    where
+        L: Iterator,
+        R: Iterator,
+        F: FnMut(&mut St, L::Item) -> Option<A>,
+        G: FnMut(&mut St, R::Item) -> Option<B>,
+    {
+        IterEither::new(match self.inner {
+            Left(inner) => Left(inner.scan(initial_state, f)),
+            Right(inner) => Right(inner.scan(initial_state, g)),
+        })
+    }
+
+    /// Creates an iterator that expands each item of a `Left` iterator into
+    /// the items of `f(item)`, as in [`Iterator::flat_map`], while the items
+    /// of a `Right` iterator are passed through.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<_, Vec<char>> = Left(vec![1, 2]);
+    /// let items: Vec<_> = left.factor_into_iter().flat_map_left(|n| vec![n; n]).collect();
+    /// assert_eq!(items, vec![Left(1), Left(2), Left(2)]);
+    ///
+    /// let right: Either<Vec<usize>, _> = Right(vec!['a']);
+    /// let items: Vec<_> = right.factor_into_iter().flat_map_left(|n| vec![n; n]).collect();
+    /// assert_eq!(items, vec![Right('a')]);
+    /// ```
+    pub fn flat_map_left<F, U>(self, f: F) -> IterEither<iter::FlatMap<L, U, F>, R>
+    where
+        L: Iterator,
+        F: FnMut(L::Item) -> U,
+        U: IntoIterator,
+    {
+        IterEither::new(self.inner.map_left(|inner| inner.flat_map(f)))
+    }
+
+    /// Creates an iterator that expands each item of a `Right` iterator into
+    /// the items of `f(item)`, as in [`Iterator::flat_map`], while the items
+    /// of a `Left` iterator are passed through.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let right: Either<Vec<char>, _> = Right(vec!["ab", "c"]);
+    /// let items: Vec<_> = right.factor_into_iter().flat_map_right(str::chars).collect();
+    /// assert_eq!(items, vec![Right('a'), Right('b'), Right('c')]);
+    ///
+    /// let left: Either<_, Vec<&str>> = Left(vec!['x']);
+    /// let items: Vec<_> = left.factor_into_iter().flat_map_right(str::chars).collect();
+    /// assert_eq!(items, vec![Left('x')]);
+    /// ```
+    pub fn flat_map_right<F, U>(self, f: F) -> IterEither<L, iter::FlatMap<R, U, F>>
+    where
+        R: Iterator,
+        F: FnMut(R::Item) -> U,
+        U: IntoIterator,
+    {
+        IterEither::new(self.inner.map_right(|inner| inner.flat_map(f)))
+    }
 }
 
 impl<L, R, A> Extend<A> for Either<L, R>
@@ -40,6 +324,19 @@ where
 }
 
 /// `Either<L, R>` is an iterator if both `L` and `R` are iterators.
+///
+/// To consume only part of the iterator, borrow it with
+/// [`by_ref`][Iterator::by_ref] and keep using the `Either` afterwards:
+///
+/// ```
+/// use either::*;
+///
+/// let mut iter: Either<_, std::vec::IntoIter<u32>> = Left(1..10);
+/// let first: Vec<_> = iter.by_ref().take(3).collect();
+/// assert_eq!(first, vec![1, 2, 3]);
+/// assert_eq!(iter.next(), Some(4));
+/// assert_eq!(iter.count(), 5);
+/// ```
 impl<L, R> Iterator for Either<L, R>
 where
     L: Iterator,
@@ -130,6 +427,16 @@ where
     {
         for_both!(*self, ref mut inner => inner.position(predicate))
     }
+
+    // `try_fold` and `try_for_each` can't be overridden on stable Rust, because
+    // their signatures use the unstable `Try` trait. Instead, the short-circuiting
+    // methods that are built on them are forwarded individually above.
+    //
+    // `collect_into` is unstable too. Extending an existing collection goes
+    // through `Extend`, which reserves from the forwarded `size_hint`.
+    //
+    // Likewise for `advance_by`, but `skip` and `nth` still reach the inner
+    // iterator through the forwarded `nth`.
 }
 
 impl<L, R> DoubleEndedIterator for Either<L, R>
@@ -267,6 +574,9 @@ where
     {
         wrap_either!(&mut self.inner => .position(predicate))
     }
+
+    // As with `Either`, `try_fold` and `try_for_each` can't be overridden on
+    // stable Rust, so the short-circuiting methods are forwarded individually.
 }
 
 impl<L, R> DoubleEndedIterator for IterEither<L, R>
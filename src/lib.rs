@@ -24,9 +24,18 @@ pub mod serde_untagged;
 #[cfg(feature = "serde")]
 pub mod serde_untagged_optional;
 
+#[cfg(feature = "serde")]
+pub mod serde_lowercase;
+
+#[cfg(feature = "serde")]
+pub mod serde_lowercase_optional;
+
+use core::cmp::Ordering;
 use core::convert::{AsMut, AsRef};
 use core::fmt;
 use core::future::Future;
+use core::hash::{Hash, Hasher};
+use core::ops;
 use core::ops::Deref;
 use core::ops::DerefMut;
 use core::pin::Pin;
@@ -44,6 +53,21 @@ pub use crate::Either::{Left, Right};
 /// The `Either` type is symmetric and treats its variants the same way, without
 /// preference.
 /// (For representing success or error, use the regular `Result` enum instead.)
+///
+/// `Either` is covariant in both `L` and `R`, so for example an
+/// `Either<&'static str, &'static [u8]>` can be used wherever an
+/// `Either<&'a str, &'a [u8]>` is expected.
+///
+/// `Either` is `Send` or `Sync` exactly when both `L` and `R` are, so one
+/// side that isn't `Send` makes the whole `Either` not `Send`:
+///
+/// ```compile_fail
+/// use either::Either;
+/// use std::{rc::Rc, sync::Arc};
+///
+/// fn assert_send<T: Send>() {}
+/// assert_send::<Either<Arc<i32>, Rc<i32>>>();
+/// ```
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
 pub enum Either<L, R> {
@@ -94,6 +118,9 @@ macro_rules! for_both {
 /// See also [`try_right!`] for its dual, which applies the same just to the
 /// right side.
 ///
+/// In functions that return `Result`, use [`Either::right_into_err`] with
+/// the `?` operator instead.
+///
 /// # Example
 ///
 /// ```
@@ -120,6 +147,9 @@ macro_rules! try_left {
 }
 
 /// Dual to [`try_left!`], see its documentation for more information.
+///
+/// In functions that return `Result`, use [`Either::left_into_err`] with
+/// the `?` operator instead.
 #[macro_export]
 macro_rules! try_right {
     ($expr:expr) => {
@@ -145,6 +175,15 @@ pub use self::iterator::IterEither;
 mod into_either;
 pub use self::into_either::IntoEither;
 
+mod either_iterator_ext;
+pub use self::either_iterator_ext::EitherIteratorExt;
+
+mod either_or_both;
+pub use self::either_or_both::EitherOrBoth;
+
+mod format;
+pub use self::format::{DebugInner, DebugLeft, DebugRight, DisplayEither};
+
 impl<L: Clone, R: Clone> Clone for Either<L, R> {
     fn clone(&self) -> Self {
         match self {
@@ -162,7 +201,132 @@ impl<L: Clone, R: Clone> Clone for Either<L, R> {
     }
 }
 
+/// The default value is `Left` with the default value of `L`.
+///
+/// This is an arbitrary choice of side. For a `Right` default, use the
+/// flipped type `Either<R, L>` and [`flip`][Either::flip] it when needed.
+///
+/// ```
+/// use either::*;
+///
+/// assert_eq!(Either::<i32, String>::default(), Left(0));
+/// ```
+impl<L: Default, R> Default for Either<L, R> {
+    fn default() -> Self {
+        Left(L::default())
+    }
+}
+
 impl<L, R> Either<L, R> {
+    /// Create a `Left` value, without needing the variant in scope.
+    ///
+    /// ```
+    /// let value = either::Either::<_, ()>::new_left(1);
+    /// assert_eq!(value, either::Left(1));
+    /// ```
+    pub fn new_left(l: L) -> Self {
+        Left(l)
+    }
+
+    /// Create a `Right` value, without needing the variant in scope.
+    ///
+    /// ```
+    /// let value = either::Either::<(), _>::new_right(1);
+    /// assert_eq!(value, either::Right(1));
+    /// ```
+    pub fn new_right(r: R) -> Self {
+        Right(r)
+    }
+
+    /// Create a `Left` value from `left` if `cond` is true, or a `Right`
+    /// value from `right` otherwise.
+    ///
+    /// Both values are evaluated before the choice; see
+    /// [`from_bool_else`][Either::from_bool_else] to only create one of them.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// assert_eq!(Either::from_bool(true, 1, "one"), Left(1));
+    /// assert_eq!(Either::from_bool(false, 1, "one"), Right("one"));
+    /// ```
+    pub fn from_bool(cond: bool, left: L, right: R) -> Self {
+        if cond {
+            Left(left)
+        } else {
+            Right(right)
+        }
+    }
+
+    /// Create a `Left` value with `left` if `cond` is true, or a `Right`
+    /// value with `right` otherwise.
+    ///
+    /// Only the chosen function is called.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let fast = true;
+    /// let iter = Either::from_bool_else(fast, || 0..3, || vec![0, 1, 2].into_iter());
+    /// assert!(iter.is_left());
+    /// assert_eq!(iter.sum::<u32>(), 3);
+    ///
+    /// let value: Either<u32, String> = Either::from_bool_else(false, || unreachable!(), || "slow".into());
+    /// assert_eq!(value, Right("slow".into()));
+    /// ```
+    pub fn from_bool_else<F, G>(cond: bool, left: F, right: G) -> Self
+    where
+        F: FnOnce() -> L,
+        G: FnOnce() -> R,
+    {
+        if cond {
+            Left(left())
+        } else {
+            Right(right())
+        }
+    }
+
+    /// Create a `Left` value by collecting an iterator into `L`.
+    ///
+    /// This makes it possible to choose the collection at runtime without boxing it.
+    ///
+    /// ```
+    /// use either::*;
+    /// use std::collections::HashSet;
+    ///
+    /// let ordered = true;
+    /// let values: Either<Vec<u32>, HashSet<u32>> = if ordered {
+    ///     Either::collect_left(vec![3, 1, 3])
+    /// } else {
+    ///     Either::collect_right(vec![3, 1, 3])
+    /// };
+    /// assert_eq!(values, Left(vec![3, 1, 3]));
+    /// ```
+    pub fn collect_left<I>(iter: I) -> Self
+    where
+        I: IntoIterator,
+        L: core::iter::FromIterator<I::Item>,
+    {
+        Left(iter.into_iter().collect())
+    }
+
+    /// Create a `Right` value by collecting an iterator into `R`.
+    ///
+    /// ```
+    /// use either::*;
+    /// use std::collections::HashSet;
+    ///
+    /// let values: Either<Vec<u32>, HashSet<u32>> = Either::collect_right(vec![3, 1, 3]);
+    /// assert_eq!(values.right().map(|set| set.len()), Some(2));
+    /// ```
+    pub fn collect_right<I>(iter: I) -> Self
+    where
+        I: IntoIterator,
+        R: core::iter::FromIterator<I::Item>,
+    {
+        Right(iter.into_iter().collect())
+    }
+
     /// Return true if the value is the `Left` variant.
     ///
     /// ```
@@ -192,8 +356,75 @@ impl<L, R> Either<L, R> {
         !self.is_left()
     }
 
+    /// Return true if the value is the `Left` variant and its value matches
+    /// the predicate `f`.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<&str, u32> = Left("");
+    /// assert_eq!(left.is_left_and(|l| l.is_empty()), true);
+    /// assert_eq!(left.is_left_and(|l| l.len() > 1), false);
+    ///
+    /// let right: Either<&str, u32> = Right(0);
+    /// assert_eq!(right.is_left_and(|l| l.is_empty()), false);
+    /// ```
+    pub fn is_left_and<F>(&self, f: F) -> bool
+    where
+        F: FnOnce(&L) -> bool,
+    {
+        match *self {
+            Left(ref l) => f(l),
+            Right(_) => false,
+        }
+    }
+
+    /// Return true if the value is the `Right` variant and its value matches
+    /// the predicate `f`.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let right: Either<&str, u32> = Right(0);
+    /// assert_eq!(right.is_right_and(|&r| r == 0), true);
+    /// assert_eq!(right.is_right_and(|&r| r > 0), false);
+    ///
+    /// let left: Either<&str, u32> = Left("");
+    /// assert_eq!(left.is_right_and(|&r| r == 0), false);
+    /// ```
+    pub fn is_right_and<F>(&self, f: F) -> bool
+    where
+        F: FnOnce(&R) -> bool,
+    {
+        match *self {
+            Left(_) => false,
+            Right(ref r) => f(r),
+        }
+    }
+
+    /// Return true if `a` and `b` are both `Left` or both `Right`, ignoring
+    /// their values.
+    ///
+    /// The values don't need to have the same types.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let a: Either<u32, &str> = Left(1);
+    /// let b: Either<String, Vec<u8>> = Left(String::from("one"));
+    /// let c: Either<char, bool> = Right(true);
+    ///
+    /// assert!(Either::same_arm(&a, &b));
+    /// assert!(!Either::same_arm(&a, &c));
+    /// ```
+    pub fn same_arm<L2, R2>(a: &Self, b: &Either<L2, R2>) -> bool {
+        a.is_left() == b.is_left()
+    }
+
     /// Convert the left side of `Either<L, R>` to an `Option<L>`.
     ///
+    /// See [`left_ref`][Self::left_ref] to borrow it instead.
+    ///
     /// ```
     /// use either::*;
     ///
@@ -212,6 +443,8 @@ impl<L, R> Either<L, R> {
 
     /// Convert the right side of `Either<L, R>` to an `Option<R>`.
     ///
+    /// See [`right_ref`][Self::right_ref] to borrow it instead.
+    ///
     /// ```
     /// use either::*;
     ///
@@ -228,8 +461,69 @@ impl<L, R> Either<L, R> {
         }
     }
 
+    /// Borrow the left side of `Either<L, R>` as an `Option<&L>`.
+    ///
+    /// This is the same as `as_ref().left()`.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<_, ()> = Left("some value");
+    /// assert_eq!(left.left_ref(), Some(&"some value"));
+    ///
+    /// let right: Either<(), _> = Right(321);
+    /// assert_eq!(right.left_ref(), None);
+    /// ```
+    pub fn left_ref(&self) -> Option<&L> {
+        match *self {
+            Left(ref l) => Some(l),
+            Right(_) => None,
+        }
+    }
+
+    /// Borrow the right side of `Either<L, R>` as an `Option<&R>`.
+    ///
+    /// This is the same as `as_ref().right()`.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<_, ()> = Left("some value");
+    /// assert_eq!(left.right_ref(), None);
+    ///
+    /// let right: Either<(), _> = Right(321);
+    /// assert_eq!(right.right_ref(), Some(&321));
+    /// ```
+    pub fn right_ref(&self) -> Option<&R> {
+        match *self {
+            Left(_) => None,
+            Right(ref r) => Some(r),
+        }
+    }
+
+    /// Convert `Either<L, R>` to `Either<Option<L>, Option<R>>`, wrapping the
+    /// value in `Some` on the same side.
+    ///
+    /// This is the inverse of [`factor_none`][Either::factor_none].
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<_, u32> = Left("some value");
+    /// assert_eq!(left.and_maybe(), Left(Some("some value")));
+    ///
+    /// let right: Either<&str, _> = Right(123);
+    /// assert_eq!(right.and_maybe(), Right(Some(123)));
+    /// assert_eq!(right.and_maybe().factor_none(), Some(right));
+    /// ```
+    pub fn and_maybe(self) -> Either<Option<L>, Option<R>> {
+        map_either!(self, inner => Some(inner))
+    }
+
     /// Convert `&Either<L, R>` to `Either<&L, &R>`.
     ///
+    /// This is the equivalent of `each_ref` on arrays.
+    ///
     /// ```
     /// use either::*;
     ///
@@ -248,6 +542,8 @@ impl<L, R> Either<L, R> {
 
     /// Convert `&mut Either<L, R>` to `Either<&mut L, &mut R>`.
     ///
+    /// This is the equivalent of `each_mut` on arrays.
+    ///
     /// ```
     /// use either::*;
     ///
@@ -271,8 +567,61 @@ impl<L, R> Either<L, R> {
         }
     }
 
+    /// Borrow the inner value as a common `&T`, using `AsRef<T>` on either side.
+    ///
+    /// This works even when `L` and `R` don't dereference to the same target,
+    /// which the `Deref` implementation would require. The target usually needs
+    /// to be given with a turbofish, as in `as_dyn::<T>()`.
+    ///
+    /// ```
+    /// use either::*;
+    /// use std::path::{Path, PathBuf};
+    ///
+    /// let name = String::from("borrowed");
+    /// let value: Either<String, &String> = Right(&name);
+    /// assert_eq!(value.as_dyn::<str>(), "borrowed");
+    ///
+    /// let path: Either<PathBuf, &str> = Right("/tmp");
+    /// assert_eq!(path.as_dyn::<Path>(), Path::new("/tmp"));
+    ///
+    /// let bytes: Either<String, Vec<u8>> = Left(String::from("abc"));
+    /// assert_eq!(bytes.as_dyn::<[u8]>(), b"abc");
+    /// ```
+    ///
+    /// There are no shorthands like `as_str`, `as_bytes` or `as_slice`, because
+    /// an inherent method would shadow the method of the same name that is
+    /// reachable through `Deref`, as on `Either<Box<String>, Box<String>>`.
+    /// Use `as_dyn::<str>()` or `as_dyn::<[T]>()` instead, and the `AsMut<[T]>`
+    /// implementation to borrow a mutable slice:
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let mut value: Either<Vec<u8>, [u8; 2]> = Right([3, 4]);
+    /// assert_eq!(value.as_dyn::<[u8]>(), [3, 4]);
+    ///
+    /// AsMut::<[u8]>::as_mut(&mut value).reverse();
+    /// assert_eq!(value, Right([4, 3]));
+    /// ```
+    pub fn as_dyn<T: ?Sized>(&self) -> &T
+    where
+        L: AsRef<T>,
+        R: AsRef<T>,
+    {
+        for_both!(*self, ref inner => inner.as_ref())
+    }
+
     /// Convert `Pin<&Either<L, R>>` to `Either<Pin<&L>, Pin<&R>>`,
     /// pinned projections of the inner variants.
+    ///
+    /// ```
+    /// use either::*;
+    /// use std::pin::Pin;
+    ///
+    /// let value: Either<u32, String> = Left(1);
+    /// let pinned = Pin::new(&value);
+    /// assert_eq!(pinned.as_pin_ref().map_left(|l| *l), Left(1));
+    /// ```
     pub fn as_pin_ref(self: Pin<&Self>) -> Either<Pin<&L>, Pin<&R>> {
         // SAFETY: We can use `new_unchecked` because the `inner` parts are
         // guaranteed to be pinned, as they come from `self` which is pinned.
@@ -286,6 +635,20 @@ impl<L, R> Either<L, R> {
 
     /// Convert `Pin<&mut Either<L, R>>` to `Either<Pin<&mut L>, Pin<&mut R>>`,
     /// pinned projections of the inner variants.
+    ///
+    /// This is what allows traits with pinned receivers, like [`Future`], to be
+    /// implemented by forwarding to whichever side is present.
+    ///
+    /// ```
+    /// use either::*;
+    /// use std::pin::Pin;
+    ///
+    /// let mut value: Either<u32, String> = Left(1);
+    /// if let Left(l) = Pin::new(&mut value).as_pin_mut() {
+    ///     *l.get_mut() += 1;
+    /// }
+    /// assert_eq!(value, Left(2));
+    /// ```
     pub fn as_pin_mut(self: Pin<&mut Self>) -> Either<Pin<&mut L>, Pin<&mut R>> {
         // SAFETY: `get_unchecked_mut` is fine because we don't move anything.
         // We can use `new_unchecked` because the `inner` parts are guaranteed
@@ -321,6 +684,9 @@ impl<L, R> Either<L, R> {
     /// Apply the function `f` on the value in the `Left` variant if it is present rewrapping the
     /// result in `Left`.
     ///
+    /// See [`left_map_or`][Either::left_map_or] to also provide a value for
+    /// the `Right` variant, without rewrapping.
+    ///
     /// ```
     /// use either::*;
     ///
@@ -343,6 +709,9 @@ impl<L, R> Either<L, R> {
     /// Apply the function `f` on the value in the `Right` variant if it is present rewrapping the
     /// result in `Right`.
     ///
+    /// See [`right_map_or`][Either::right_map_or] to also provide a value for
+    /// the `Left` variant, without rewrapping.
+    ///
     /// ```
     /// use either::*;
     ///
@@ -362,102 +731,528 @@ impl<L, R> Either<L, R> {
         }
     }
 
-    /// Apply the functions `f` and `g` to the `Left` and `Right` variants
-    /// respectively. This is equivalent to
-    /// [bimap](https://hackage.haskell.org/package/bifunctors-5/docs/Data-Bifunctor.html)
-    /// in functional programming.
+    /// Move the value in the `Left` variant to the heap, rewrapping it in `Left`.
+    ///
+    /// This is useful to build recursive types, where the recursive side has
+    /// to be boxed.
     ///
     /// ```
     /// use either::*;
     ///
-    /// let f = |s: String| s.len();
-    /// let g = |u: u8| u.to_string();
+    /// struct Tree(Either<Box<(Tree, Tree)>, u32>);
     ///
-    /// let left: Either<String, u8> = Left("loopy".into());
-    /// assert_eq!(left.map_either(f, g), Left(5));
+    /// fn branch(a: Tree, b: Tree) -> Tree {
+    ///     Tree(Left((a, b)).boxed_left())
+    /// }
     ///
-    /// let right: Either<String, u8> = Right(42);
-    /// assert_eq!(right.map_either(f, g), Right("42".into()));
+    /// fn leaf(value: u32) -> Tree {
+    ///     Tree(Right(value))
+    /// }
+    ///
+    /// fn sum(tree: &Tree) -> u32 {
+    ///     match tree.0 {
+    ///         Left(ref children) => sum(&children.0) + sum(&children.1),
+    ///         Right(value) => value,
+    ///     }
+    /// }
+    ///
+    /// let tree = branch(leaf(1), branch(leaf(2), leaf(3)));
+    /// assert_eq!(sum(&tree), 6);
     /// ```
-    pub fn map_either<F, G, M, S>(self, f: F, g: G) -> Either<M, S>
-    where
-        F: FnOnce(L) -> M,
-        G: FnOnce(R) -> S,
-    {
-        match self {
-            Left(l) => Left(f(l)),
-            Right(r) => Right(g(r)),
-        }
+    ///
+    /// Requires crate feature `"use_std"`
+    #[cfg(any(test, feature = "use_std"))]
+    pub fn boxed_left(self) -> Either<std::boxed::Box<L>, R> {
+        self.map_left(std::boxed::Box::new)
     }
 
-    /// Similar to [`map_either`][Self::map_either], with an added context `ctx` accessible to
-    /// both functions.
+    /// Move the value in the `Right` variant to the heap, rewrapping it in `Right`.
     ///
     /// ```
     /// use either::*;
     ///
-    /// let mut sum = 0;
+    /// let left: Either<_, u32> = Left(123);
+    /// assert_eq!(left.boxed_right(), Left(123));
     ///
-    /// // Both closures want to update the same value, so pass it as context.
-    /// let mut f = |sum: &mut usize, s: String| { *sum += s.len(); s.to_uppercase() };
-    /// let mut g = |sum: &mut usize, u: usize| { *sum += u; u.to_string() };
+    /// let right: Either<u32, _> = Right(123);
+    /// assert_eq!(right.boxed_right(), Right(Box::new(123)));
+    /// ```
     ///
-    /// let left: Either<String, usize> = Left("loopy".into());
-    /// assert_eq!(left.map_either_with(&mut sum, &mut f, &mut g), Left("LOOPY".into()));
+    /// Requires crate feature `"use_std"`
+    #[cfg(any(test, feature = "use_std"))]
+    pub fn boxed_right(self) -> Either<L, std::boxed::Box<R>> {
+        self.map_right(std::boxed::Box::new)
+    }
+
+    /// Apply the function `f` on the value in the `Left` variant if it is
+    /// present, or return `default` for a `Right` value.
     ///
-    /// let right: Either<String, usize> = Right(42);
-    /// assert_eq!(right.map_either_with(&mut sum, &mut f, &mut g), Right("42".into()));
+    /// ```
+    /// use either::*;
     ///
-    /// assert_eq!(sum, 47);
+    /// let left: Either<&str, u32> = Left("hello");
+    /// assert_eq!(left.left_map_or(0, |l| l.len()), 5);
+    ///
+    /// let right: Either<&str, u32> = Right(3);
+    /// assert_eq!(right.left_map_or(0, |l| l.len()), 0);
     /// ```
-    pub fn map_either_with<Ctx, F, G, M, S>(self, ctx: Ctx, f: F, g: G) -> Either<M, S>
+    pub fn left_map_or<F, M>(self, default: M, f: F) -> M
     where
-        F: FnOnce(Ctx, L) -> M,
-        G: FnOnce(Ctx, R) -> S,
+        F: FnOnce(L) -> M,
     {
         match self {
-            Left(l) => Left(f(ctx, l)),
-            Right(r) => Right(g(ctx, r)),
+            Left(l) => f(l),
+            Right(_) => default,
         }
     }
 
-    /// Apply one of two functions depending on contents, unifying their result. If the value is
-    /// `Left(L)` then the first function `f` is applied; if it is `Right(R)` then the second
-    /// function `g` is applied.
+    /// Apply the function `f` on the value in the `Left` variant if it is
+    /// present, or compute a default from the `Right` value with `default`.
     ///
     /// ```
     /// use either::*;
     ///
-    /// fn square(n: u32) -> i32 { (n * n) as i32 }
-    /// fn negate(n: i32) -> i32 { -n }
-    ///
-    /// let left: Either<u32, i32> = Left(4);
-    /// assert_eq!(left.either(square, negate), 16);
+    /// let left: Either<&str, u32> = Left("hello");
+    /// assert_eq!(left.left_map_or_else(|r| r as usize, |l| l.len()), 5);
     ///
-    /// let right: Either<u32, i32> = Right(-4);
-    /// assert_eq!(right.either(square, negate), 4);
+    /// let right: Either<&str, u32> = Right(3);
+    /// assert_eq!(right.left_map_or_else(|r| r as usize, |l| l.len()), 3);
     /// ```
-    pub fn either<F, G, T>(self, f: F, g: G) -> T
+    pub fn left_map_or_else<D, F, M>(self, default: D, f: F) -> M
     where
-        F: FnOnce(L) -> T,
-        G: FnOnce(R) -> T,
+        D: FnOnce(R) -> M,
+        F: FnOnce(L) -> M,
     {
         match self {
             Left(l) => f(l),
-            Right(r) => g(r),
+            Right(r) => default(r),
         }
     }
 
-    /// Like [`either`][Self::either], but provide some context to whichever of the
-    /// functions ends up being called.
+    /// Apply the function `f` on the value in the `Right` variant if it is
+    /// present, or return `default` for a `Left` value.
     ///
     /// ```
-    /// // In this example, the context is a mutable reference
     /// use either::*;
     ///
-    /// let mut result = Vec::new();
-    ///
-    /// let values = vec![Left(2), Right(2.7)];
+    /// let left: Either<u32, &str> = Left(3);
+    /// assert_eq!(left.right_map_or(0, |r| r.len()), 0);
+    ///
+    /// let right: Either<u32, &str> = Right("hello");
+    /// assert_eq!(right.right_map_or(0, |r| r.len()), 5);
+    /// ```
+    pub fn right_map_or<F, S>(self, default: S, f: F) -> S
+    where
+        F: FnOnce(R) -> S,
+    {
+        match self {
+            Left(_) => default,
+            Right(r) => f(r),
+        }
+    }
+
+    /// Apply the function `f` on the value in the `Right` variant if it is
+    /// present, or compute a default from the `Left` value with `default`.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<u32, &str> = Left(3);
+    /// assert_eq!(left.right_map_or_else(|l| l as usize, |r| r.len()), 3);
+    ///
+    /// let right: Either<u32, &str> = Right("hello");
+    /// assert_eq!(right.right_map_or_else(|l| l as usize, |r| r.len()), 5);
+    /// ```
+    pub fn right_map_or_else<D, F, S>(self, default: D, f: F) -> S
+    where
+        D: FnOnce(L) -> S,
+        F: FnOnce(R) -> S,
+    {
+        match self {
+            Left(l) => default(l),
+            Right(r) => f(r),
+        }
+    }
+
+    /// Apply the functions `f` and `g` to the `Left` and `Right` variants
+    /// respectively. This is equivalent to
+    /// [bimap](https://hackage.haskell.org/package/bifunctors-5/docs/Data-Bifunctor.html)
+    /// in functional programming.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let f = |s: String| s.len();
+    /// let g = |u: u8| u.to_string();
+    ///
+    /// let left: Either<String, u8> = Left("loopy".into());
+    /// assert_eq!(left.map_either(f, g), Left(5));
+    ///
+    /// let right: Either<String, u8> = Right(42);
+    /// assert_eq!(right.map_either(f, g), Right("42".into()));
+    /// ```
+    pub fn map_either<F, G, M, S>(self, f: F, g: G) -> Either<M, S>
+    where
+        F: FnOnce(L) -> M,
+        G: FnOnce(R) -> S,
+    {
+        match self {
+            Left(l) => Left(f(l)),
+            Right(r) => Right(g(r)),
+        }
+    }
+
+    /// Apply the functions `f` and `g` to the `Left` and `Right` variants
+    /// respectively.
+    ///
+    /// This is an alias of [`map_either`][Self::map_either], for those used to
+    /// the name from functional programming.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<&str, u8> = Left("loopy");
+    /// assert_eq!(left.bimap(str::len, u32::from), Left(5));
+    /// ```
+    pub fn bimap<F, G, M, S>(self, f: F, g: G) -> Either<M, S>
+    where
+        F: FnOnce(L) -> M,
+        G: FnOnce(R) -> S,
+    {
+        self.map_either(f, g)
+    }
+
+    /// Convert the `Left` value into `M` or the `Right` value into `S`
+    /// through `Into`, keeping the same variant.
+    ///
+    /// The target types usually can't be inferred, so they're typically given
+    /// with a turbofish, as in `map_into::<M, S>()`, or by the surrounding context.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<u8, u16> = Left(1);
+    /// assert_eq!(left.map_into::<u32, u64>(), Left(1u32));
+    ///
+    /// let right: Either<u8, u16> = Right(2);
+    /// let wide: Either<u32, u64> = right.map_into();
+    /// assert_eq!(wide, Right(2u64));
+    /// ```
+    pub fn map_into<M, S>(self) -> Either<M, S>
+    where
+        L: Into<M>,
+        R: Into<S>,
+    {
+        self.map_either(Into::into, Into::into)
+    }
+
+    /// Try to convert the `Left` value into `M` or the `Right` value into `S`
+    /// through `TryInto`, keeping the same variant.
+    ///
+    /// If the conversion fails, the error is returned on the same side as the
+    /// value that couldn't be converted.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<i64, u64> = Left(-3);
+    /// assert_eq!(left.try_map_into::<i32, u32>(), Ok(Left(-3i32)));
+    ///
+    /// let right: Either<i64, u64> = Right(1 << 40);
+    /// assert!(right.try_map_into::<i32, u32>().unwrap_err().is_right());
+    /// ```
+    pub fn try_map_into<M, S>(self) -> Result<Either<M, S>, Either<L::Error, R::Error>>
+    where
+        L: core::convert::TryInto<M>,
+        R: core::convert::TryInto<S>,
+    {
+        match self {
+            Left(l) => l.try_into().map(Left).map_err(Left),
+            Right(r) => r.try_into().map(Right).map_err(Right),
+        }
+    }
+
+    /// Similar to [`map_either`][Self::map_either], with an added context `ctx` accessible to
+    /// both functions.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let mut sum = 0;
+    ///
+    /// // Both closures want to update the same value, so pass it as context.
+    /// let mut f = |sum: &mut usize, s: String| { *sum += s.len(); s.to_uppercase() };
+    /// let mut g = |sum: &mut usize, u: usize| { *sum += u; u.to_string() };
+    ///
+    /// let left: Either<String, usize> = Left("loopy".into());
+    /// assert_eq!(left.map_either_with(&mut sum, &mut f, &mut g), Left("LOOPY".into()));
+    ///
+    /// let right: Either<String, usize> = Right(42);
+    /// assert_eq!(right.map_either_with(&mut sum, &mut f, &mut g), Right("42".into()));
+    ///
+    /// assert_eq!(sum, 47);
+    /// ```
+    pub fn map_either_with<Ctx, F, G, M, S>(self, ctx: Ctx, f: F, g: G) -> Either<M, S>
+    where
+        F: FnOnce(Ctx, L) -> M,
+        G: FnOnce(Ctx, R) -> S,
+    {
+        match self {
+            Left(l) => Left(f(ctx, l)),
+            Right(r) => Right(g(ctx, r)),
+        }
+    }
+
+    /// Combine two `Either` values on the same side, applying `f` to a pair of
+    /// `Left` values or `g` to a pair of `Right` values.
+    ///
+    /// Returns `None` if `self` and `other` are on different sides.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let f = |a: u32, b: u32| a + b;
+    /// let g = |a: String, b: &str| a + b;
+    ///
+    /// let left: Either<u32, String> = Left(1);
+    /// assert_eq!(left.zip_with(Left(2), f, g), Some(Left(3)));
+    ///
+    /// let right: Either<u32, String> = Right("a".into());
+    /// assert_eq!(right.zip_with(Right("b"), f, g), Some(Right("ab".into())));
+    ///
+    /// let right: Either<u32, String> = Right("a".into());
+    /// assert_eq!(right.zip_with(Left(2), f, g), None);
+    /// ```
+    pub fn zip_with<C, D, F, G, M, S>(self, other: Either<C, D>, f: F, g: G) -> Option<Either<M, S>>
+    where
+        F: FnOnce(L, C) -> M,
+        G: FnOnce(R, D) -> S,
+    {
+        match (self, other) {
+            (Left(l), Left(c)) => Some(Left(f(l, c))),
+            (Right(r), Right(d)) => Some(Right(g(r, d))),
+            _ => None,
+        }
+    }
+
+    /// Pair two `Either` values on the same side, as `Left((l, c))` or
+    /// `Right((r, d))`.
+    ///
+    /// If `self` and `other` are on different sides, both are returned
+    /// unchanged in the error.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<u32, &str> = Left(1);
+    /// let other: Either<char, u8> = Left('a');
+    /// assert_eq!(left.zip_strict(other), Ok(Left((1, 'a'))));
+    ///
+    /// let right: Either<u32, &str> = Right("b");
+    /// let other: Either<char, u8> = Left('a');
+    /// assert_eq!(right.zip_strict(other), Err((right, other)));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn zip_strict<C, D>(
+        self,
+        other: Either<C, D>,
+    ) -> Result<Either<(L, C), (R, D)>, (Either<L, R>, Either<C, D>)> {
+        match (self, other) {
+            (Left(l), Left(c)) => Ok(Left((l, c))),
+            (Right(r), Right(d)) => Ok(Right((r, d))),
+            mismatch => Err(mismatch),
+        }
+    }
+
+    /// Split the value into two halves with `f` for a `Left` value or `g` for
+    /// a `Right` value, returning each half on the same side.
+    ///
+    /// This is useful to split duplex IO types into a reading and a writing
+    /// half, keeping an `Either` for each.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let pair: Either<(u32, char), &str> = Left((1, 'a'));
+    /// let (first, second) = pair.split_with(|pair| pair, |s| s.split_at(1));
+    /// assert_eq!(first, Left(1));
+    /// assert_eq!(second, Left('a'));
+    ///
+    /// let text: Either<(u32, char), &str> = Right("key=value");
+    /// let (key, value) = text.split_with(|pair| pair, |s| s.split_at(3));
+    /// assert_eq!(key, Right("key"));
+    /// assert_eq!(value, Right("=value"));
+    /// ```
+    pub fn split_with<F, G, LA, LB, RA, RB>(self, f: F, g: G) -> (Either<LA, RA>, Either<LB, RB>)
+    where
+        F: FnOnce(L) -> (LA, LB),
+        G: FnOnce(R) -> (RA, RB),
+    {
+        match self {
+            Left(l) => {
+                let (a, b) = f(l);
+                (Left(a), Left(b))
+            }
+            Right(r) => {
+                let (a, b) = g(r);
+                (Right(a), Right(b))
+            }
+        }
+    }
+
+    /// Apply one of two functions depending on contents, unifying their result. If the value is
+    /// `Left(L)` then the first function `f` is applied; if it is `Right(R)` then the second
+    /// function `g` is applied.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// fn square(n: u32) -> i32 { (n * n) as i32 }
+    /// fn negate(n: i32) -> i32 { -n }
+    ///
+    /// let left: Either<u32, i32> = Left(4);
+    /// assert_eq!(left.either(square, negate), 16);
+    ///
+    /// let right: Either<u32, i32> = Right(-4);
+    /// assert_eq!(right.either(square, negate), 4);
+    /// ```
+    ///
+    /// The functions can be fallible, as long as they return the same
+    /// `Result` type, so the outcome can be propagated with `?`:
+    ///
+    /// ```
+    /// use either::*;
+    /// use std::num::ParseIntError;
+    ///
+    /// fn value(input: Either<&str, i64>) -> Result<i64, String> {
+    ///     let n = input.either(
+    ///         |s| s.parse().map_err(|e: ParseIntError| e.to_string()),
+    ///         |n| if n >= 0 { Ok(n) } else { Err("negative".to_string()) },
+    ///     )?;
+    ///     Ok(n * 10)
+    /// }
+    ///
+    /// assert_eq!(value(Left("4")), Ok(40));
+    /// assert!(value(Left("four")).is_err());
+    /// assert_eq!(value(Right(2)), Ok(20));
+    /// assert_eq!(value(Right(-2)), Err("negative".to_string()));
+    /// ```
+    ///
+    /// This also collapses two different types into one boxed trait object,
+    /// when each side is boxed and coerced in its own function. If `Either`
+    /// itself implements the trait, like `Display` or `Iterator` here, the
+    /// whole `Either` can be boxed instead, without a match:
+    ///
+    /// ```
+    /// use either::*;
+    /// use std::fmt::Display;
+    ///
+    /// let value: Either<u32, &'static str> = Left(3);
+    ///
+    /// let each: Box<dyn Display> = value.either(|l| Box::new(l) as _, |r| Box::new(r) as _);
+    /// assert_eq!(each.to_string(), "3");
+    ///
+    /// let whole: Box<dyn Display> = Box::new(value);
+    /// assert_eq!(whole.to_string(), "3");
+    /// ```
+    ///
+    /// To collapse a borrowed value without consuming it, see
+    /// [`with_ref`][Self::with_ref].
+    // TODO(MSRV): doc(alias) was stabilized in Rust 1.48
+    // #[doc(alias = "collapse")]
+    pub fn either<F, G, T>(self, f: F, g: G) -> T
+    where
+        F: FnOnce(L) -> T,
+        G: FnOnce(R) -> T,
+    {
+        match self {
+            Left(l) => f(l),
+            Right(r) => g(r),
+        }
+    }
+
+    /// Like [`either`][Self::either], but borrow the value instead of
+    /// consuming it, applying `f` to a `Left` reference or `g` to a `Right`
+    /// reference.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let sorted: Either<Vec<u32>, &[u32]> = Left(vec![1, 2, 4, 8]);
+    /// let search = |x: &[u32]| x.binary_search(&3).unwrap_or_else(|i| i);
+    /// assert_eq!(sorted.with_ref(|l| search(l), |r| search(r)), 2);
+    ///
+    /// let words: Either<Vec<u32>, &str> = Right("hello");
+    /// assert_eq!(words.with_ref(|l| l.len(), |r| r.len()), 5);
+    /// ```
+    ///
+    /// This also covers lookups in collections that don't share a trait, like
+    /// different kinds of maps:
+    ///
+    /// ```
+    /// use either::*;
+    /// use std::collections::{BTreeMap, HashMap};
+    ///
+    /// let mut map = BTreeMap::new();
+    /// map.insert("a", 1);
+    /// let map: Either<HashMap<&str, i32>, _> = Right(map);
+    /// assert_eq!(map.with_ref(|l| l.get("a"), |r| r.get("a")), Some(&1));
+    /// ```
+    pub fn with_ref<'a, F, G, T>(&'a self, f: F, g: G) -> T
+    where
+        F: FnOnce(&'a L) -> T,
+        G: FnOnce(&'a R) -> T,
+    {
+        match *self {
+            Left(ref l) => f(l),
+            Right(ref r) => g(r),
+        }
+    }
+
+    /// Like [`either`][Self::either], but borrow the value mutably instead of
+    /// consuming it, applying `f` to a `Left` reference or `g` to a `Right`
+    /// reference.
+    ///
+    /// See [`modify`][Self::modify] when there's no result to return.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let mut value: Either<Vec<u32>, String> = Left(vec![1, 2]);
+    /// let len = value.with_mut(
+    ///     |l| {
+    ///         l.push(3);
+    ///         l.len()
+    ///     },
+    ///     |r| {
+    ///         r.push('!');
+    ///         r.len()
+    ///     },
+    /// );
+    /// assert_eq!(len, 3);
+    /// assert_eq!(value, Left(vec![1, 2, 3]));
+    /// ```
+    pub fn with_mut<'a, F, G, T>(&'a mut self, f: F, g: G) -> T
+    where
+        F: FnOnce(&'a mut L) -> T,
+        G: FnOnce(&'a mut R) -> T,
+    {
+        match *self {
+            Left(ref mut l) => f(l),
+            Right(ref mut r) => g(r),
+        }
+    }
+
+    /// Like [`either`][Self::either], but provide some context to whichever of the
+    /// functions ends up being called.
+    ///
+    /// ```
+    /// // In this example, the context is a mutable reference
+    /// use either::*;
+    ///
+    /// let mut result = Vec::new();
+    ///
+    /// let values = vec![Left(2), Right(2.7)];
     ///
     /// for value in values {
     ///     value.either_with(&mut result,
@@ -465,16 +1260,285 @@ impl<L, R> Either<L, R> {
     ///                       |ctx, real| ctx.push(f64::round(real) as i32));
     /// }
     ///
-    /// assert_eq!(result, vec![2, 3]);
+    /// assert_eq!(result, vec![2, 3]);
+    /// ```
+    pub fn either_with<Ctx, F, G, T>(self, ctx: Ctx, f: F, g: G) -> T
+    where
+        F: FnOnce(Ctx, L) -> T,
+        G: FnOnce(Ctx, R) -> T,
+    {
+        match self {
+            Left(l) => f(ctx, l),
+            Right(r) => g(ctx, r),
+        }
+    }
+
+    /// Modify the contents in place, applying `f` to a `Left` value or `g` to a
+    /// `Right` value by mutable reference.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let mut left: Either<Vec<u32>, String> = Left(vec![1, 2, 3]);
+    /// left.modify(|l| l.clear(), |r| r.clear());
+    /// assert_eq!(left, Left(vec![]));
+    ///
+    /// let mut right: Either<Vec<u32>, String> = Right("abc".into());
+    /// right.modify(|l| l.push(4), |r| r.push('d'));
+    /// assert_eq!(right, Right("abcd".into()));
+    /// ```
+    pub fn modify<F, G>(&mut self, f: F, g: G)
+    where
+        F: FnOnce(&mut L),
+        G: FnOnce(&mut R),
+    {
+        match *self {
+            Left(ref mut l) => f(l),
+            Right(ref mut r) => g(r),
+        }
+    }
+
+    /// Apply `f` to the value by mutable reference if it is `Left`, and do
+    /// nothing otherwise.
+    ///
+    /// See [`modify`][Either::modify] to handle both variants.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let mut values: Vec<Either<u32, &str>> = vec![Left(1), Right("a"), Left(2)];
+    /// for value in &mut values {
+    ///     value.for_left(|l| *l *= 10);
+    /// }
+    /// assert_eq!(values, vec![Left(10), Right("a"), Left(20)]);
+    /// ```
+    pub fn for_left<F>(&mut self, f: F)
+    where
+        F: FnOnce(&mut L),
+    {
+        if let Left(ref mut l) = *self {
+            f(l);
+        }
+    }
+
+    /// Apply `f` to the value by mutable reference if it is `Right`, and do
+    /// nothing otherwise.
+    ///
+    /// See [`modify`][Either::modify] to handle both variants.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let mut values: Vec<Either<u32, String>> = vec![Left(1), Right("a".into())];
+    /// for value in &mut values {
+    ///     value.for_right(|r| r.push('!'));
+    /// }
+    /// assert_eq!(values, vec![Left(1), Right("a!".into())]);
+    /// ```
+    pub fn for_right<F>(&mut self, f: F)
+    where
+        F: FnOnce(&mut R),
+    {
+        if let Right(ref mut r) = *self {
+            f(r);
+        }
+    }
+
+    /// Return an adapter that formats the value with `Display`, using `left`
+    /// for a `Left` value and `right` for a `Right` value.
+    ///
+    /// Unlike the `Display` implementation of `Either`, this doesn't require
+    /// either side to implement `Display` itself.
+    ///
+    /// ```
+    /// use either::*;
+    /// use std::fmt::Write;
+    ///
+    /// let values: [Either<u32, Vec<u8>>; 2] = [Left(1), Right(vec![1, 2])];
+    /// let mut output = String::new();
+    /// for value in &values {
+    ///     let display = value.display_with(
+    ///         |l, f| write!(f, "number {}", l),
+    ///         |r, f| write!(f, "{} bytes", r.len()),
+    ///     );
+    ///     writeln!(output, "{}", display).unwrap();
+    /// }
+    /// assert_eq!(output, "number 1\n2 bytes\n");
+    /// ```
+    pub fn display_with<F, G>(&self, left: F, right: G) -> DisplayEither<'_, L, R, F, G>
+    where
+        F: Fn(&L, &mut fmt::Formatter<'_>) -> fmt::Result,
+        G: Fn(&R, &mut fmt::Formatter<'_>) -> fmt::Result,
+    {
+        DisplayEither::new(self, left, right)
+    }
+
+    /// Return an adapter that formats the value with `Debug` if it is `Left`,
+    /// and prints `Right(<opaque>)` otherwise.
+    ///
+    /// This is useful when the right side doesn't implement `Debug`.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<u32, Box<dyn Fn()>> = Left(1);
+    /// assert_eq!(format!("{:?}", left.debug_left()), "Left(1)");
+    ///
+    /// let right: Either<u32, Box<dyn Fn()>> = Right(Box::new(|| {}));
+    /// assert_eq!(format!("{:?}", right.debug_left()), "Right(<opaque>)");
+    /// ```
+    pub fn debug_left(&self) -> DebugLeft<'_, L, R>
+    where
+        L: fmt::Debug,
+    {
+        DebugLeft::new(self)
+    }
+
+    /// Return an adapter that formats the value with `Debug` if it is `Right`,
+    /// and prints `Left(<opaque>)` otherwise.
+    ///
+    /// This is useful when the left side doesn't implement `Debug`.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<Box<dyn Fn()>, u32> = Left(Box::new(|| {}));
+    /// assert_eq!(format!("{:?}", left.debug_right()), "Left(<opaque>)");
+    ///
+    /// let right: Either<Box<dyn Fn()>, u32> = Right(2);
+    /// assert_eq!(format!("{:?}", right.debug_right()), "Right(2)");
+    /// ```
+    pub fn debug_right(&self) -> DebugRight<'_, L, R>
+    where
+        R: fmt::Debug,
+    {
+        DebugRight::new(self)
+    }
+
+    /// Return an adapter that formats the value with `Debug`, without the
+    /// `Left` or `Right` label.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let value: Either<u32, &str> = Right("text");
+    /// assert_eq!(format!("{:?}", value), r#"Right("text")"#);
+    /// assert_eq!(format!("{:?}", value.debug_inner()), r#""text""#);
+    /// ```
+    pub fn debug_inner(&self) -> DebugInner<'_, L, R>
+    where
+        L: fmt::Debug,
+        R: fmt::Debug,
+    {
+        DebugInner::new(self)
+    }
+
+    /// Feed the value into `state`, preceded by an explicit tag byte: `0u8`
+    /// for `Left` and `1u8` for `Right`.
+    ///
+    /// Unlike the derived `Hash` implementation, which hashes the enum
+    /// discriminant in an unspecified way, the bytes written for the tag are
+    /// guaranteed to stay the same across versions of this crate and of the
+    /// compiler. The value itself is hashed with its own `Hash` implementation.
+    ///
+    /// ```
+    /// use either::*;
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::{Hash, Hasher};
+    ///
+    /// let mut left_hasher = DefaultHasher::new();
+    /// Either::<u32, u32>::Left(7).stable_hash(&mut left_hasher);
+    ///
+    /// let mut tuple_hasher = DefaultHasher::new();
+    /// (0u8, 7u32).hash(&mut tuple_hasher);
+    ///
+    /// assert_eq!(left_hasher.finish(), tuple_hasher.finish());
+    /// ```
+    pub fn stable_hash<H>(&self, state: &mut H)
+    where
+        L: Hash,
+        R: Hash,
+        H: Hasher,
+    {
+        match *self {
+            Left(ref l) => {
+                state.write_u8(0);
+                l.hash(state);
+            }
+            Right(ref r) => {
+                state.write_u8(1);
+                r.hash(state);
+            }
+        }
+    }
+
+    /// Feed only a `Left` value into `state`, and the same constant for any
+    /// `Right` value.
+    ///
+    /// This uses the same tag bytes as [`stable_hash`][Either::stable_hash],
+    /// but skips the `Right` value, so all `Right` values hash identically.
+    /// That's useful to key a custom `Hash` implementation on the `Left`
+    /// values, with every `Right` value in a single bucket.
+    ///
+    /// ```
+    /// use either::*;
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::Hasher;
+    ///
+    /// fn hash_left(value: Either<u32, &str>) -> u64 {
+    ///     let mut hasher = DefaultHasher::new();
+    ///     value.hash_left(&mut hasher);
+    ///     hasher.finish()
+    /// }
+    ///
+    /// assert_eq!(hash_left(Right("a")), hash_left(Right("b")));
+    /// assert_ne!(hash_left(Left(1)), hash_left(Left(2)));
     /// ```
-    pub fn either_with<Ctx, F, G, T>(self, ctx: Ctx, f: F, g: G) -> T
+    pub fn hash_left<H>(&self, state: &mut H)
     where
-        F: FnOnce(Ctx, L) -> T,
-        G: FnOnce(Ctx, R) -> T,
+        L: Hash,
+        H: Hasher,
     {
-        match self {
-            Left(l) => f(ctx, l),
-            Right(r) => g(ctx, r),
+        match *self {
+            Left(ref l) => {
+                state.write_u8(0);
+                l.hash(state);
+            }
+            Right(_) => state.write_u8(1),
+        }
+    }
+
+    /// Feed only a `Right` value into `state`, and the same constant for any
+    /// `Left` value.
+    ///
+    /// This uses the same tag bytes as [`stable_hash`][Either::stable_hash],
+    /// but skips the `Left` value, so all `Left` values hash identically.
+    ///
+    /// ```
+    /// use either::*;
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::Hasher;
+    ///
+    /// fn hash_right(value: Either<&str, u32>) -> u64 {
+    ///     let mut hasher = DefaultHasher::new();
+    ///     value.hash_right(&mut hasher);
+    ///     hasher.finish()
+    /// }
+    ///
+    /// assert_eq!(hash_right(Left("a")), hash_right(Left("b")));
+    /// assert_ne!(hash_right(Right(1)), hash_right(Right(2)));
+    /// ```
+    pub fn hash_right<H>(&self, state: &mut H)
+    where
+        R: Hash,
+        H: Hasher,
+    {
+        match *self {
+            Left(_) => state.write_u8(0),
+            Right(ref r) => {
+                state.write_u8(1);
+                r.hash(state);
+            }
         }
     }
 
@@ -520,6 +1584,281 @@ impl<L, R> Either<L, R> {
         }
     }
 
+    /// Apply the function `f` on the value in the `Left` variant if it is
+    /// present, or map the value in the `Right` variant with `g`.
+    ///
+    /// This generalizes [`left_and_then`][Either::left_and_then] to change
+    /// the `Right` type as well.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// // Parse a number, or fall back to the length of the text.
+    /// fn parse(s: &str) -> Either<i32, usize> {
+    ///     match s.parse() {
+    ///         Ok(n) => Left(n),
+    ///         Err(_) => Right(s.len()),
+    ///     }
+    /// }
+    ///
+    /// let left: Either<&str, u8> = Left("12");
+    /// assert_eq!(left.left_and_then_or(parse, usize::from), Left(12));
+    ///
+    /// let left: Either<&str, u8> = Left("twelve");
+    /// assert_eq!(left.left_and_then_or(parse, usize::from), Right(6));
+    ///
+    /// let right: Either<&str, u8> = Right(3);
+    /// assert_eq!(right.left_and_then_or(parse, usize::from), Right(3));
+    /// ```
+    pub fn left_and_then_or<F, G, M, S>(self, f: F, g: G) -> Either<M, S>
+    where
+        F: FnOnce(L) -> Either<M, S>,
+        G: FnOnce(R) -> S,
+    {
+        match self {
+            Left(l) => f(l),
+            Right(r) => Right(g(r)),
+        }
+    }
+
+    /// Apply the function `g` on the value in the `Right` variant if it is
+    /// present, or map the value in the `Left` variant with `f`.
+    ///
+    /// This generalizes [`right_and_then`][Either::right_and_then] to change
+    /// the `Left` type as well.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// // Parse a number, or fall back to the length of the text.
+    /// fn parse(s: &str) -> Either<usize, i32> {
+    ///     match s.parse() {
+    ///         Ok(n) => Right(n),
+    ///         Err(_) => Left(s.len()),
+    ///     }
+    /// }
+    ///
+    /// let right: Either<u8, &str> = Right("12");
+    /// assert_eq!(right.right_and_then_or(usize::from, parse), Right(12));
+    ///
+    /// let right: Either<u8, &str> = Right("twelve");
+    /// assert_eq!(right.right_and_then_or(usize::from, parse), Left(6));
+    ///
+    /// let left: Either<u8, &str> = Left(3);
+    /// assert_eq!(left.right_and_then_or(usize::from, parse), Left(3));
+    /// ```
+    pub fn right_and_then_or<F, G, M, S>(self, f: F, g: G) -> Either<M, S>
+    where
+        F: FnOnce(L) -> M,
+        G: FnOnce(R) -> Either<M, S>,
+    {
+        match self {
+            Left(l) => Left(f(l)),
+            Right(r) => g(r),
+        }
+    }
+
+    /// Apply the fallible function `f` on the value in the `Left` variant if
+    /// it is present, switching to `Right` with the error if it fails.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let validate = |s: &str| s.parse::<u32>().map_err(|_| s.len());
+    ///
+    /// let left: Either<&str, usize> = Left("12");
+    /// assert_eq!(left.map_left_or_else_right(validate), Left(12));
+    ///
+    /// let left: Either<&str, usize> = Left("twelve");
+    /// assert_eq!(left.map_left_or_else_right(validate), Right(6));
+    ///
+    /// let right: Either<&str, usize> = Right(3);
+    /// assert_eq!(right.map_left_or_else_right(validate), Right(3));
+    /// ```
+    pub fn map_left_or_else_right<F, M>(self, f: F) -> Either<M, R>
+    where
+        F: FnOnce(L) -> Result<M, R>,
+    {
+        match self {
+            Left(l) => match f(l) {
+                Ok(m) => Left(m),
+                Err(r) => Right(r),
+            },
+            Right(r) => Right(r),
+        }
+    }
+
+    /// Apply the fallible function `f` on the value in the `Right` variant if
+    /// it is present, switching to `Left` with the error if it fails.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let validate = |s: &str| s.parse::<u32>().map_err(|_| s.len());
+    ///
+    /// let right: Either<usize, &str> = Right("12");
+    /// assert_eq!(right.map_right_or_else_left(validate), Right(12));
+    ///
+    /// let right: Either<usize, &str> = Right("twelve");
+    /// assert_eq!(right.map_right_or_else_left(validate), Left(6));
+    ///
+    /// let left: Either<usize, &str> = Left(3);
+    /// assert_eq!(left.map_right_or_else_left(validate), Left(3));
+    /// ```
+    pub fn map_right_or_else_left<F, S>(self, f: F) -> Either<L, S>
+    where
+        F: FnOnce(R) -> Result<S, L>,
+    {
+        match self {
+            Left(l) => Left(l),
+            Right(r) => match f(r) {
+                Ok(s) => Right(s),
+                Err(l) => Left(l),
+            },
+        }
+    }
+
+    /// Return `None` if the value is `Left` and doesn't match the predicate `f`,
+    /// otherwise return `Some(self)`.
+    ///
+    /// A `Right` value is never filtered. Use `left().filter(f)` to discard it as well.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let values = vec![Left("a"), Right(1), Left(""), Right(2)];
+    /// let filtered: Vec<_> = values
+    ///     .into_iter()
+    ///     .filter_map(|x| x.filter_left(|l| !l.is_empty()))
+    ///     .collect();
+    /// assert_eq!(filtered, vec![Left("a"), Right(1), Right(2)]);
+    /// ```
+    pub fn filter_left<F>(self, f: F) -> Option<Self>
+    where
+        F: FnOnce(&L) -> bool,
+    {
+        match self {
+            Left(l) => {
+                if f(&l) {
+                    Some(Left(l))
+                } else {
+                    None
+                }
+            }
+            Right(r) => Some(Right(r)),
+        }
+    }
+
+    /// Return `None` if the value is `Right` and doesn't match the predicate `f`,
+    /// otherwise return `Some(self)`.
+    ///
+    /// A `Left` value is never filtered. Use `right().filter(f)` to discard it as well.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let values = vec![Left("a"), Right(1), Left(""), Right(2)];
+    /// let filtered: Vec<_> = values
+    ///     .into_iter()
+    ///     .filter_map(|x| x.filter_right(|&r| r > 1))
+    ///     .collect();
+    /// assert_eq!(filtered, vec![Left("a"), Left(""), Right(2)]);
+    /// ```
+    pub fn filter_right<F>(self, f: F) -> Option<Self>
+    where
+        F: FnOnce(&R) -> bool,
+    {
+        match self {
+            Left(l) => Some(Left(l)),
+            Right(r) => {
+                if f(&r) {
+                    Some(Right(r))
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    /// Return `Some(self)` if the value matches the predicate for its side,
+    /// `f` for `Left` or `g` for `Right`, otherwise return `None`.
+    ///
+    /// Exactly one of the predicates is called.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let values = vec![Left("a"), Right(1), Left(""), Right(20)];
+    /// let kept: Vec<_> = values
+    ///     .into_iter()
+    ///     .filter_map(|x| x.keep_if(|l| !l.is_empty(), |&r| r < 10))
+    ///     .collect();
+    /// assert_eq!(kept, vec![Left("a"), Right(1)]);
+    /// ```
+    pub fn keep_if<F, G>(self, f: F, g: G) -> Option<Self>
+    where
+        F: FnOnce(&L) -> bool,
+        G: FnOnce(&R) -> bool,
+    {
+        let keep = match self {
+            Left(ref l) => f(l),
+            Right(ref r) => g(r),
+        };
+        if keep {
+            Some(self)
+        } else {
+            None
+        }
+    }
+
+    /// Return `self` if it is `Left`, otherwise return `other`.
+    ///
+    /// This prefers a `Left` value, falling back to `other` which may be either
+    /// side. Arguments passed to `or` are eagerly evaluated; if you are passing
+    /// the result of a function call, it is recommended to use
+    /// [`or_else`][Self::or_else], which is lazily evaluated.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<u32, &str> = Left(1);
+    /// assert_eq!(left.or(Left(2)), Left(1));
+    ///
+    /// let right: Either<u32, &str> = Right("a");
+    /// assert_eq!(right.or(Left(2)), Left(2));
+    /// assert_eq!(right.or(Right("b")), Right("b"));
+    /// ```
+    pub fn or(self, other: Either<L, R>) -> Either<L, R> {
+        match self {
+            Left(l) => Left(l),
+            Right(_) => other,
+        }
+    }
+
+    /// Return `self` if it is `Left`, otherwise compute a replacement with `f`.
+    ///
+    /// This is the lazily evaluated version of [`or`][Self::or]. To use the
+    /// `Right` value in the replacement, see [`right_and_then`][Self::right_and_then].
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<u32, &str> = Left(1);
+    /// assert_eq!(left.or_else(|| unreachable!()), Left(1));
+    ///
+    /// let right: Either<u32, &str> = Right("a");
+    /// assert_eq!(right.or_else(|| Left(2)), Left(2));
+    /// ```
+    pub fn or_else<F>(self, f: F) -> Either<L, R>
+    where
+        F: FnOnce() -> Either<L, R>,
+    {
+        match self {
+            Left(l) => Left(l),
+            Right(_) => f(),
+        }
+    }
+
     /// Convert the inner value to an iterator.
     ///
     /// This requires the `Left` and `Right` iterators to have the same item type.
@@ -565,6 +1904,30 @@ impl<L, R> Either<L, R> {
         map_either!(self, inner => inner.into_iter())
     }
 
+    /// Borrow the inner value as a slice, and iterate over clones of its items.
+    ///
+    /// This requires the `Left` and `Right` values to both be viewable as `[T]`,
+    /// like arrays, vectors and slices.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let data = [1, 2];
+    /// let left: Either<&[u32], Vec<u32>> = Left(&data);
+    /// let right: Either<&[u32], Vec<u32>> = Right(vec![3]);
+    /// let mut all: Vec<u32> = left.iter_cloned().collect();
+    /// all.extend(right.iter_cloned());
+    /// assert_eq!(all, vec![1, 2, 3]);
+    /// ```
+    pub fn iter_cloned<T>(&self) -> core::iter::Cloned<core::slice::Iter<'_, T>>
+    where
+        L: AsRef<[T]>,
+        R: AsRef<[T]>,
+        T: Clone,
+    {
+        self.as_dyn::<[T]>().iter().cloned()
+    }
+
     /// Mutably borrow the inner value as an iterator.
     ///
     /// This requires the `Left` and `Right` iterators to have the same item type.
@@ -605,11 +1968,27 @@ impl<L, R> Either<L, R> {
     /// use either::*;
     /// let left: Either<_, Vec<u8>> = Left(&["hello"]);
     /// assert_eq!(left.factor_into_iter().next(), Some(Left(&"hello")));
-
+    ///
     /// let right: Either<&[&str], _> = Right(vec![0, 1]);
     /// assert_eq!(right.factor_into_iter().collect::<Vec<_>>(), vec![Right(0), Right(1)]);
     ///
     /// ```
+    ///
+    /// Maps are iterators over key-value pairs, so they are factored into
+    /// `Either` pairs:
+    ///
+    /// ```
+    /// use either::*;
+    /// use std::collections::{BTreeMap, HashMap};
+    ///
+    /// let mut map = BTreeMap::new();
+    /// map.insert("a", 1.5);
+    /// map.insert("b", 2.5);
+    /// let store: Either<HashMap<&str, u32>, _> = Right(map);
+    ///
+    /// let pairs: Vec<Either<(&str, u32), (&str, f64)>> = store.factor_into_iter().collect();
+    /// assert_eq!(pairs, vec![Right(("a", 1.5)), Right(("b", 2.5))]);
+    /// ```
     // TODO(MSRV): doc(alias) was stabilized in Rust 1.48
     // #[doc(alias = "transpose")]
     pub fn factor_into_iter(self) -> IterEither<L::IntoIter, R::IntoIter>
@@ -629,7 +2008,7 @@ impl<L, R> Either<L, R> {
     /// use either::*;
     /// let left: Either<_, Vec<u8>> = Left(["hello"]);
     /// assert_eq!(left.factor_iter().next(), Some(Left(&"hello")));
-
+    ///
     /// let right: Either<[&str; 2], _> = Right(vec![0, 1]);
     /// assert_eq!(right.factor_iter().collect::<Vec<_>>(), vec![Right(&0), Right(&1)]);
     ///
@@ -654,7 +2033,7 @@ impl<L, R> Either<L, R> {
     /// let mut left: Either<_, Vec<u8>> = Left(["hello"]);
     /// left.factor_iter_mut().for_each(|x| *x.unwrap_left() = "goodbye");
     /// assert_eq!(left, Left(["goodbye"]));
-
+    ///
     /// let mut right: Either<[&str; 2], _> = Right(vec![0, 1, 2]);
     /// right.factor_iter_mut().for_each(|x| if let Right(r) = x { *r = -*r; });
     /// assert_eq!(right, Right(vec![0, -1, -2]));
@@ -670,6 +2049,39 @@ impl<L, R> Either<L, R> {
         IterEither::new(map_either!(self, inner => inner.into_iter()))
     }
 
+    /// Converts an `Either` of `Iterator`s to be an `Iterator` of a common type `T`
+    ///
+    /// Unlike [`into_iter`][Either::into_iter], this does not require the `Left`
+    /// and `Right` iterators to have the same item type, only that both can be
+    /// converted into `T` with `Into`. The target type usually can't be inferred,
+    /// so it's given with a turbofish, as in `factor_into_iter_into::<T>()`.
+    ///
+    /// ```
+    /// use either::*;
+    /// let left: Either<Vec<u32>, Vec<u64>> = Left(vec![1, 2]);
+    /// let sum: u64 = left.factor_into_iter_into::<u64>().sum();
+    /// assert_eq!(sum, 3);
+    ///
+    /// let right: Either<Vec<u32>, Vec<u64>> = Right(vec![u64::max_value()]);
+    /// let all: Vec<_> = right.factor_into_iter_into::<u64>().collect();
+    /// assert_eq!(all, vec![u64::max_value()]);
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn factor_into_iter_into<T>(
+        self,
+    ) -> Either<
+        core::iter::Map<L::IntoIter, fn(L::Item) -> T>,
+        core::iter::Map<R::IntoIter, fn(R::Item) -> T>,
+    >
+    where
+        L: IntoIterator,
+        R: IntoIterator,
+        L::Item: Into<T>,
+        R::Item: Into<T>,
+    {
+        map_either!(self, inner => inner.into_iter().map(Into::into as fn(_) -> T))
+    }
+
     /// Return left value or given value
     ///
     /// Arguments passed to `left_or` are eagerly evaluated; if you are passing
@@ -717,15 +2129,46 @@ impl<L, R> Either<L, R> {
 
     /// Returns left value or computes it from a closure
     ///
+    /// This normalizes either side to the `Left` type. Wrap the result in
+    /// `Left` if code expecting an `Either` needs it on a single side.
+    ///
     /// # Examples
     ///
     /// ```
     /// # use either::*;
-    /// let left: Either<String, u32> = Left("3".to_string());
-    /// assert_eq!(left.left_or_else(|_| unreachable!()), "3");
+    /// let left: Either<String, u32> = Left("3".to_string());
+    /// assert_eq!(left.left_or_else(|_| unreachable!()), "3");
+    ///
+    /// let right: Either<String, u32> = Right(3);
+    /// assert_eq!(right.left_or_else(|x| x.to_string()), "3");
+    /// ```
+    ///
+    /// With a success value on the `Left` and an error on the `Right`, this is
+    /// the counterpart of [`Result::unwrap_or_else`]. Together with
+    /// [`left_and_then`][Either::left_and_then] and [`map_left`][Either::map_left]
+    /// it covers a `Result`-like pipeline, and [`try_left!`] plays the role of
+    /// the `?` operator:
+    ///
+    /// ```
+    /// # use either::*;
+    /// fn parse(s: &str) -> Either<u32, String> {
+    ///     match s.parse() {
+    ///         Ok(n) => Left(n),
+    ///         Err(_) => Right(format!("not a number: {}", s)),
+    ///     }
+    /// }
     ///
-    /// let right: Either<String, u32> = Right(3);
-    /// assert_eq!(right.left_or_else(|x| x.to_string()), "3");
+    /// fn double(s: &str) -> Either<u32, String> {
+    ///     let n = try_left!(parse(s));
+    ///     Left(n * 2)
+    /// }
+    ///
+    /// let half = |n: u32| if n % 2 == 0 { Left(n / 2) } else { Right("odd".into()) };
+    /// assert_eq!(parse("8").left_and_then(half), Left(4));
+    /// assert_eq!(parse("7").left_and_then(half), Right("odd".into()));
+    ///
+    /// assert_eq!(double("21").map_left(|n| n + 1), Left(43));
+    /// assert_eq!(double("x").left_or_else(|_| 0), 0);
     /// ```
     pub fn left_or_else<F>(self, f: F) -> L
     where
@@ -784,6 +2227,9 @@ impl<L, R> Either<L, R> {
 
     /// Returns right value or computes it from a closure
     ///
+    /// This normalizes either side to the `Right` type. Wrap the result in
+    /// `Right` if code expecting an `Either` needs it on a single side.
+    ///
     /// # Examples
     ///
     /// ```
@@ -869,100 +2315,470 @@ impl<L, R> Either<L, R> {
     /// # Examples
     ///
     /// ```
-    /// # use either::*;
-    /// let left: Either<_, ()> = Left(3);
-    /// assert_eq!(left.expect_left("value was Right"), 3);
-    /// ```
-    ///
-    /// # Panics
+    /// # use either::*;
+    /// let left: Either<_, ()> = Left(3);
+    /// assert_eq!(left.expect_left("value was Right"), 3);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// When `Either` is a `Right` value
+    ///
+    /// ```should_panic
+    /// # use either::*;
+    /// let right: Either<(), _> = Right(3);
+    /// right.expect_left("value was Right");
+    /// ```
+    pub fn expect_left(self, msg: &str) -> L
+    where
+        R: core::fmt::Debug,
+    {
+        match self {
+            Either::Left(l) => l,
+            Either::Right(r) => panic!("{}: {:?}", msg, r),
+        }
+    }
+
+    /// Returns the right value
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use either::*;
+    /// let right: Either<(), _> = Right(3);
+    /// assert_eq!(right.expect_right("value was Left"), 3);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// When `Either` is a `Left` value
+    ///
+    /// ```should_panic
+    /// # use either::*;
+    /// let left: Either<_, ()> = Left(3);
+    /// left.expect_right("value was Right");
+    /// ```
+    pub fn expect_right(self, msg: &str) -> R
+    where
+        L: core::fmt::Debug,
+    {
+        match self {
+            Either::Right(r) => r,
+            Either::Left(l) => panic!("{}: {:?}", msg, l),
+        }
+    }
+
+    /// Returns the left value, or panics with a message computed from the
+    /// right value by `f`
+    ///
+    /// Unlike [`expect_left`][Self::expect_left], this doesn't require `R: Debug`,
+    /// and the message can include any data from the `Right` value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use either::*;
+    /// let left: Either<_, u32> = Left(3);
+    /// assert_eq!(left.unwrap_left_or_else_panic(|r| format!("unexpected right: {}", r)), 3);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// When `Either` is a `Right` value
+    ///
+    /// ```
+    /// # use either::*;
+    /// use std::panic;
+    ///
+    /// let result = panic::catch_unwind(|| {
+    ///     let right: Either<(), _> = Right(vec![1, 2]);
+    ///     right.unwrap_left_or_else_panic(|r| format!("unexpected right: {:?}", r))
+    /// });
+    /// let payload = result.unwrap_err();
+    /// assert_eq!(payload.downcast_ref::<String>().unwrap(), "unexpected right: [1, 2]");
+    /// ```
+    pub fn unwrap_left_or_else_panic<F, M>(self, f: F) -> L
+    where
+        F: FnOnce(R) -> M,
+        M: fmt::Display,
+    {
+        match self {
+            Either::Left(l) => l,
+            Either::Right(r) => panic!("{}", f(r)),
+        }
+    }
+
+    /// Returns the right value, or panics with a message computed from the
+    /// left value by `f`
+    ///
+    /// Unlike [`expect_right`][Self::expect_right], this doesn't require `L: Debug`,
+    /// and the message can include any data from the `Left` value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use either::*;
+    /// let right: Either<u32, _> = Right(3);
+    /// assert_eq!(right.unwrap_right_or_else_panic(|l| format!("unexpected left: {}", l)), 3);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// When `Either` is a `Left` value
+    ///
+    /// ```
+    /// # use either::*;
+    /// use std::panic;
+    ///
+    /// let result = panic::catch_unwind(|| {
+    ///     let left: Either<_, ()> = Left(vec![1, 2]);
+    ///     left.unwrap_right_or_else_panic(|l| format!("unexpected left: {:?}", l))
+    /// });
+    /// let payload = result.unwrap_err();
+    /// assert_eq!(payload.downcast_ref::<String>().unwrap(), "unexpected left: [1, 2]");
+    /// ```
+    pub fn unwrap_right_or_else_panic<F, M>(self, f: F) -> R
+    where
+        F: FnOnce(L) -> M,
+        M: fmt::Display,
+    {
+        match self {
+            Either::Right(r) => r,
+            Either::Left(l) => panic!("{}", f(l)),
+        }
+    }
+
+    /// Convert the contained value into `T`
+    ///
+    /// This collapses either side into a common type through `Into`. The target
+    /// usually can't be inferred from the arguments, so it's typically given
+    /// with a turbofish, as in `either_into::<T>()`, or by the surrounding context.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use either::*;
+    /// // Both u16 and u32 can be converted to u64.
+    /// let left: Either<u16, u32> = Left(3u16);
+    /// assert_eq!(left.either_into::<u64>(), 3u64);
+    /// let right: Either<u16, u32> = Right(7u32);
+    /// assert_eq!(right.either_into::<u64>(), 7u64);
+    /// ```
+    pub fn either_into<T>(self) -> T
+    where
+        L: Into<T>,
+        R: Into<T>,
+    {
+        match self {
+            Either::Left(l) => l.into(),
+            Either::Right(r) => r.into(),
+        }
+    }
+
+    /// Try to convert the contained value into `T`
+    ///
+    /// This is the fallible counterpart of [`either_into`][Either::either_into],
+    /// through `TryInto`. If the conversion fails, the error is returned on the
+    /// same side as the value that couldn't be converted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use either::*;
+    /// // Both i64 and u64 can be converted to u8, if they are in range.
+    /// let left: Either<i64, u64> = Left(3);
+    /// assert_eq!(left.try_either_into::<u8>(), Ok(3u8));
+    /// let left: Either<i64, u64> = Left(-1);
+    /// assert!(left.try_either_into::<u8>().unwrap_err().is_left());
+    /// let right: Either<i64, u64> = Right(256);
+    /// assert!(right.try_either_into::<u8>().unwrap_err().is_right());
+    /// ```
+    pub fn try_either_into<T>(self) -> Result<T, Either<L::Error, R::Error>>
+    where
+        L: core::convert::TryInto<T>,
+        R: core::convert::TryInto<T>,
+    {
+        match self {
+            Either::Left(l) => l.try_into().map_err(Either::Left),
+            Either::Right(r) => r.try_into().map_err(Either::Right),
+        }
+    }
+
+    /// Convert `Either<L, R>` to `Result<R, L>`, with `Left` as the error.
+    ///
+    /// This is the same conversion as the `Into<Result<R, L>>` implementation,
+    /// but named so it's clear which side becomes `Err`. See
+    /// [`right_into_err`][Self::right_into_err] for the opposite direction.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<&str, u32> = Left("error");
+    /// assert_eq!(left.left_into_err(), Err("error"));
+    ///
+    /// let right: Either<&str, u32> = Right(3);
+    /// assert_eq!(right.left_into_err(), Ok(3));
+    /// ```
+    ///
+    /// With the `?` operator, this unwraps the `Right` value or returns early
+    /// with the `Left` value, like [`try_right!`] does in functions that return
+    /// `Either`:
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// fn twice(value: Either<&str, u32>) -> Result<u32, String> {
+    ///     let n = value.left_into_err()?;
+    ///     Ok(n * 2)
+    /// }
+    ///
+    /// assert_eq!(twice(Right(2)), Ok(4));
+    /// assert_eq!(twice(Left("error")), Err("error".to_string()));
+    /// ```
+    pub fn left_into_err(self) -> Result<R, L> {
+        match self {
+            Left(l) => Err(l),
+            Right(r) => Ok(r),
+        }
+    }
+
+    /// Convert `Either<L, R>` to `Result<L, R>`, with `Right` as the error.
+    ///
+    /// See [`left_into_err`][Self::left_into_err] for the opposite direction.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<u32, &str> = Left(3);
+    /// assert_eq!(left.right_into_err(), Ok(3));
+    ///
+    /// let right: Either<u32, &str> = Right("error");
+    /// assert_eq!(right.right_into_err(), Err("error"));
+    /// ```
+    ///
+    /// With the `?` operator, this unwraps the `Left` value or returns early
+    /// with the `Right` value, like [`try_left!`] does in functions that return
+    /// `Either`:
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// fn twice(value: Either<u32, &str>) -> Result<u32, String> {
+    ///     let n = value.right_into_err()?;
+    ///     Ok(n * 2)
+    /// }
+    ///
+    /// assert_eq!(twice(Left(2)), Ok(4));
+    /// assert_eq!(twice(Right("error")), Err("error".to_string()));
+    /// ```
+    pub fn right_into_err(self) -> Result<L, R> {
+        match self {
+            Left(l) => Ok(l),
+            Right(r) => Err(r),
+        }
+    }
+
+    /// Convert `Either<L, R>` to `Result<T, E>`, applying `ok` to a `Left` value
+    /// and `err` to a `Right` value.
+    ///
+    /// Use [`flip`][Self::flip] first to make `Right` the `Ok` value instead.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<&str, u32> = Left("12");
+    /// assert_eq!(left.into_result_with(str::len, |r| r + 1), Ok(2));
+    ///
+    /// let right: Either<&str, u32> = Right(7);
+    /// assert_eq!(right.flip().into_result_with(|r| r + 1, str::len), Ok(8));
+    /// ```
+    pub fn into_result_with<F, G, T, E>(self, ok: F, err: G) -> Result<T, E>
+    where
+        F: FnOnce(L) -> T,
+        G: FnOnce(R) -> E,
+    {
+        match self {
+            Left(l) => Ok(ok(l)),
+            Right(r) => Err(err(r)),
+        }
+    }
+
+    /// Borrow the value as a `Result<&R, &L>`, with `Right` as `Ok`, like
+    /// [`left_into_err`][Either::left_into_err] does by value.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<&str, u32> = Left("error");
+    /// assert_eq!(left.as_result(), Err(&"error"));
+    ///
+    /// let right: Either<&str, u32> = Right(3);
+    /// assert_eq!(right.as_result().map(|r| r * 2), Ok(6));
+    /// ```
+    pub fn as_result(&self) -> Result<&R, &L> {
+        match *self {
+            Left(ref l) => Err(l),
+            Right(ref r) => Ok(r),
+        }
+    }
+
+    /// Mutably borrow the value as a `Result<&mut R, &mut L>`, with `Right`
+    /// as `Ok`.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let mut left: Either<String, u32> = Left("error".into());
+    /// if let Err(l) = left.as_result_mut() {
+    ///     l.push('!');
+    /// }
+    /// assert_eq!(left, Left("error!".into()));
+    ///
+    /// let mut right: Either<String, u32> = Right(3);
+    /// if let Ok(r) = right.as_result_mut() {
+    ///     *r += 1;
+    /// }
+    /// assert_eq!(right, Right(4));
+    /// ```
+    pub fn as_result_mut(&mut self) -> Result<&mut R, &mut L> {
+        match *self {
+            Left(ref mut l) => Err(l),
+            Right(ref mut r) => Ok(r),
+        }
+    }
+}
+
+impl<L, R> Either<Option<L>, Option<R>> {
+    /// Factors out `None` from an `Either` of [`Option`].
+    ///
+    /// ```
+    /// use either::*;
+    /// let left: Either<_, Option<String>> = Left(Some(vec![0]));
+    /// assert_eq!(left.factor_none(), Some(Left(vec![0])));
+    ///
+    /// let right: Either<Option<Vec<u8>>, _> = Right(Some(String::new()));
+    /// assert_eq!(right.factor_none(), Some(Right(String::new())));
+    /// ```
+    // TODO(MSRV): doc(alias) was stabilized in Rust 1.48
+    // #[doc(alias = "transpose")]
+    pub fn factor_none(self) -> Option<Either<L, R>> {
+        match self {
+            Left(l) => l.map(Either::Left),
+            Right(r) => r.map(Either::Right),
+        }
+    }
+
+    /// Takes the value out of the active variant, leaving `None` in its place.
+    ///
+    /// The variant itself is preserved, like [`Option::take`] on the inner value.
+    ///
+    /// ```
+    /// use either::*;
+    /// let mut left: Either<_, Option<String>> = Left(Some(vec![0]));
+    /// assert_eq!(left.take_inner(), Some(Left(vec![0])));
+    /// assert_eq!(left, Left(None));
+    /// assert_eq!(left.take_inner(), None);
+    ///
+    /// let mut right: Either<Option<Vec<u8>>, _> = Right(Some(String::new()));
+    /// assert_eq!(right.take_inner(), Some(Right(String::new())));
+    /// assert_eq!(right, Right(None));
+    /// ```
+    pub fn take_inner(&mut self) -> Option<Either<L, R>> {
+        match *self {
+            Left(ref mut l) => l.take().map(Either::Left),
+            Right(ref mut r) => r.take().map(Either::Right),
+        }
+    }
+}
+
+impl<T> Either<Option<T>, T> {
+    /// Returns the optional `Left` value as is, or a `Right` value in `Some`.
+    ///
+    /// ```
+    /// use either::*;
+    /// let left: Either<Option<u32>, u32> = Left(Some(1));
+    /// assert_eq!(left.flatten_option(), Some(1));
     ///
-    /// When `Either` is a `Right` value
+    /// let left: Either<Option<u32>, u32> = Left(None);
+    /// assert_eq!(left.flatten_option(), None);
     ///
-    /// ```should_panic
-    /// # use either::*;
-    /// let right: Either<(), _> = Right(3);
-    /// right.expect_left("value was Right");
+    /// let right: Either<Option<u32>, u32> = Right(2);
+    /// assert_eq!(right.flatten_option(), Some(2));
     /// ```
-    pub fn expect_left(self, msg: &str) -> L
-    where
-        R: core::fmt::Debug,
-    {
+    pub fn flatten_option(self) -> Option<T> {
         match self {
-            Either::Left(l) => l,
-            Either::Right(r) => panic!("{}: {:?}", msg, r),
+            Left(l) => l,
+            Right(r) => Some(r),
         }
     }
+}
 
-    /// Returns the right value
-    ///
-    /// # Examples
+impl<T> Either<T, Option<T>> {
+    /// Returns a `Left` value in `Some`, or the optional `Right` value as is.
     ///
     /// ```
-    /// # use either::*;
-    /// let right: Either<(), _> = Right(3);
-    /// assert_eq!(right.expect_right("value was Left"), 3);
-    /// ```
-    ///
-    /// # Panics
+    /// use either::*;
+    /// let left: Either<u32, Option<u32>> = Left(1);
+    /// assert_eq!(left.flatten_option(), Some(1));
     ///
-    /// When `Either` is a `Left` value
+    /// let right: Either<u32, Option<u32>> = Right(Some(2));
+    /// assert_eq!(right.flatten_option(), Some(2));
     ///
-    /// ```should_panic
-    /// # use either::*;
-    /// let left: Either<_, ()> = Left(3);
-    /// left.expect_right("value was Right");
+    /// let right: Either<u32, Option<u32>> = Right(None);
+    /// assert_eq!(right.flatten_option(), None);
     /// ```
-    pub fn expect_right(self, msg: &str) -> R
-    where
-        L: core::fmt::Debug,
-    {
+    pub fn flatten_option(self) -> Option<T> {
         match self {
-            Either::Right(r) => r,
-            Either::Left(l) => panic!("{}: {:?}", msg, l),
+            Left(l) => Some(l),
+            Right(r) => r,
         }
     }
+}
 
-    /// Convert the contained value into `T`
-    ///
-    /// # Examples
+impl<A, B, C> Either<Either<A, B>, C> {
+    /// Move the `Right` side of a nested `Left` into the outer `Right`,
+    /// converting `Either<Either<A, B>, C>` to `Either<A, Either<B, C>>`.
     ///
     /// ```
-    /// # use either::*;
-    /// // Both u16 and u32 can be converted to u64.
-    /// let left: Either<u16, u32> = Left(3u16);
-    /// assert_eq!(left.either_into::<u64>(), 3u64);
-    /// let right: Either<u16, u32> = Right(7u32);
-    /// assert_eq!(right.either_into::<u64>(), 7u64);
+    /// use either::*;
+    /// let value: Either<Either<u8, char>, &str> = Left(Left(1));
+    /// assert_eq!(value.flatten_left(), Left(1));
+    ///
+    /// let value: Either<Either<u8, char>, &str> = Left(Right('b'));
+    /// assert_eq!(value.flatten_left(), Right(Left('b')));
+    ///
+    /// let value: Either<Either<u8, char>, &str> = Right("c");
+    /// assert_eq!(value.flatten_left(), Right(Right("c")));
     /// ```
-    pub fn either_into<T>(self) -> T
-    where
-        L: Into<T>,
-        R: Into<T>,
-    {
+    pub fn flatten_left(self) -> Either<A, Either<B, C>> {
         match self {
-            Either::Left(l) => l.into(),
-            Either::Right(r) => r.into(),
+            Left(Left(a)) => Left(a),
+            Left(Right(b)) => Right(Left(b)),
+            Right(c) => Right(Right(c)),
         }
     }
 }
 
-impl<L, R> Either<Option<L>, Option<R>> {
-    /// Factors out `None` from an `Either` of [`Option`].
+impl<A, B, C> Either<A, Either<B, C>> {
+    /// Move the `Left` side of a nested `Right` into the outer `Left`,
+    /// converting `Either<A, Either<B, C>>` to `Either<Either<A, B>, C>`.
     ///
     /// ```
     /// use either::*;
-    /// let left: Either<_, Option<String>> = Left(Some(vec![0]));
-    /// assert_eq!(left.factor_none(), Some(Left(vec![0])));
+    /// let value: Either<u8, Either<char, &str>> = Left(1);
+    /// assert_eq!(value.flatten_right(), Left(Left(1)));
     ///
-    /// let right: Either<Option<Vec<u8>>, _> = Right(Some(String::new()));
-    /// assert_eq!(right.factor_none(), Some(Right(String::new())));
+    /// let value: Either<u8, Either<char, &str>> = Right(Left('b'));
+    /// assert_eq!(value.flatten_right(), Left(Right('b')));
+    ///
+    /// let value: Either<u8, Either<char, &str>> = Right(Right("c"));
+    /// assert_eq!(value.flatten_right(), Right("c"));
     /// ```
-    // TODO(MSRV): doc(alias) was stabilized in Rust 1.48
-    // #[doc(alias = "transpose")]
-    pub fn factor_none(self) -> Option<Either<L, R>> {
+    pub fn flatten_right(self) -> Either<Either<A, B>, C> {
         match self {
-            Left(l) => l.map(Either::Left),
-            Right(r) => r.map(Either::Right),
+            Left(a) => Left(Left(a)),
+            Right(Left(b)) => Left(Right(b)),
+            Right(Right(c)) => Right(c),
         }
     }
 }
@@ -1058,6 +2874,9 @@ impl<T, L, R> Either<(L, T), (R, T)> {
 impl<T> Either<T, T> {
     /// Extract the value of an either over two equivalent types.
     ///
+    /// See [`either_into`][Either::either_into] to collapse two different types
+    /// into a common one.
+    ///
     /// ```
     /// use either::*;
     ///
@@ -1067,6 +2886,16 @@ impl<T> Either<T, T> {
     /// let right: Either<u32, _> = Right(123);
     /// assert_eq!(right.into_inner(), 123);
     /// ```
+    ///
+    /// This also collapses the outer layer of a nested `Either` whose sides
+    /// share the same inner `Either` type:
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let nested: Either<Either<u32, &str>, Either<u32, &str>> = Right(Left(1));
+    /// assert_eq!(nested.into_inner(), Left(1));
+    /// ```
     pub fn into_inner(self) -> T {
         for_both!(self, inner => inner)
     }
@@ -1074,6 +2903,9 @@ impl<T> Either<T, T> {
     /// Map `f` over the contained value and return the result in the
     /// corresponding variant.
     ///
+    /// Unlike [`either`][Either::either] or [`into_inner`][Either::into_inner],
+    /// this keeps track of which variant held the value.
+    ///
     /// ```
     /// use either::*;
     ///
@@ -1081,6 +2913,9 @@ impl<T> Either<T, T> {
     ///
     /// let other = value.map(|x| x * 2);
     /// assert_eq!(other, Right(84));
+    ///
+    /// let value: Either<i32, _> = Left(2);
+    /// assert_eq!(value.map(|x| x * 2), Left(4));
     /// ```
     pub fn map<F, M>(self, f: F) -> Either<M, M>
     where
@@ -1091,9 +2926,53 @@ impl<T> Either<T, T> {
             Right(r) => Right(f(r)),
         }
     }
+
+    /// Switch the variant in place, keeping the contained value.
+    ///
+    /// This is the in-place counterpart of [`flip`][Either::flip], which is
+    /// possible when both sides have the same type.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let mut value: Either<_, String> = Left(String::from("on"));
+    /// value.swap_arms();
+    /// assert_eq!(value, Right(String::from("on")));
+    /// value.swap_arms();
+    /// assert_eq!(value, Left(String::from("on")));
+    /// ```
+    pub fn swap_arms(&mut self) {
+        // SAFETY: The value read from `self` is written back before `self` is
+        // used again, and nothing in between can panic, so it's never dropped
+        // or observed twice.
+        unsafe {
+            let flipped = core::ptr::read(self).flip();
+            core::ptr::write(self, flipped);
+        }
+    }
+
+    /// Switch the variant if `cond` is true, otherwise return `self` unchanged.
+    ///
+    /// Unlike [`flip`][Either::flip], the type stays the same, so this can
+    /// toggle a two-state value based on a runtime flag.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let value: Either<_, u32> = Left(1);
+    /// assert_eq!(value.flip_if(true), Right(1));
+    /// assert_eq!(value.flip_if(false), Left(1));
+    /// ```
+    pub fn flip_if(self, cond: bool) -> Self {
+        if cond {
+            self.flip()
+        } else {
+            self
+        }
+    }
 }
 
-impl<L, R> Either<&L, &R> {
+impl<L: ?Sized, R: ?Sized> Either<&L, &R> {
     /// Maps an `Either<&L, &R>` to an `Either<L, R>` by cloning the contents of
     /// either branch.
     pub fn cloned(self) -> Either<L, R>
@@ -1119,6 +2998,34 @@ impl<L, R> Either<&L, &R> {
             Self::Right(r) => Either::Right(*r),
         }
     }
+
+    /// Maps an `Either<&L, &R>` to an `Either<L::Owned, R::Owned>` with `ToOwned`.
+    ///
+    /// Unlike [`cloned`][Either::cloned], this also works for unsized types,
+    /// like `str` to `String` and `[T]` to `Vec<T>`.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<&str, &[u8]> = Left("hello");
+    /// assert_eq!(left.to_owned_either(), Left(String::from("hello")));
+    ///
+    /// let right: Either<&str, &[u8]> = Right(&[1, 2, 3]);
+    /// assert_eq!(right.to_owned_either(), Right(vec![1, 2, 3]));
+    /// ```
+    ///
+    /// Requires crate feature `"use_std"`
+    #[cfg(any(test, feature = "use_std"))]
+    pub fn to_owned_either(self) -> Either<L::Owned, R::Owned>
+    where
+        L: std::borrow::ToOwned,
+        R: std::borrow::ToOwned,
+    {
+        match self {
+            Self::Left(l) => Either::Left(l.to_owned()),
+            Self::Right(r) => Either::Right(r.to_owned()),
+        }
+    }
 }
 
 impl<L, R> Either<&mut L, &mut R> {
@@ -1149,7 +3056,63 @@ impl<L, R> Either<&mut L, &mut R> {
     }
 }
 
+impl<T> Either<T, &T> {
+    /// Returns the owned `Left` value, or clones the borrowed `Right` value.
+    ///
+    /// This treats the `Either` like a `Cow` with `Left` as the owned side, for
+    /// types that only need `Clone`.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let text = String::from("borrowed");
+    /// let right: Either<String, &String> = Right(&text);
+    /// assert_eq!(right.into_owned(), "borrowed");
+    ///
+    /// let left: Either<String, &String> = Left("owned".into());
+    /// assert_eq!(left.into_owned(), "owned");
+    /// ```
+    pub fn into_owned(self) -> T
+    where
+        T: Clone,
+    {
+        match self {
+            Left(l) => l,
+            Right(r) => r.clone(),
+        }
+    }
+}
+
+impl<T> Either<&T, T> {
+    /// Returns the owned `Right` value, or clones the borrowed `Left` value.
+    ///
+    /// This treats the `Either` like a `Cow` with `Right` as the owned side, for
+    /// types that only need `Clone`.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let text = String::from("borrowed");
+    /// let left: Either<&String, String> = Left(&text);
+    /// assert_eq!(left.into_owned(), "borrowed");
+    ///
+    /// let right: Either<&String, String> = Right("owned".into());
+    /// assert_eq!(right.into_owned(), "owned");
+    /// ```
+    pub fn into_owned(self) -> T
+    where
+        T: Clone,
+    {
+        match self {
+            Left(l) => l.clone(),
+            Right(r) => r,
+        }
+    }
+}
+
 /// Convert from `Result` to `Either` with `Ok => Right` and `Err => Left`.
+///
+/// To go back, use [`Either::left_into_err`], which names the direction explicitly.
 impl<L, R> From<Result<R, L>> for Either<L, R> {
     fn from(r: Result<R, L>) -> Self {
         match r {
@@ -1160,6 +3123,9 @@ impl<L, R> From<Result<R, L>> for Either<L, R> {
 }
 
 /// Convert from `Either` to `Result` with `Right => Ok` and `Left => Err`.
+///
+/// The same conversion is available as [`Either::left_into_err`], and
+/// [`Either::right_into_err`] converts with the opposite bias.
 #[allow(clippy::from_over_into)] // From requires RFC 2451, Rust 1.41
 impl<L, R> Into<Result<R, L>> for Either<L, R> {
     fn into(self) -> Result<R, L> {
@@ -1170,7 +3136,135 @@ impl<L, R> Into<Result<R, L>> for Either<L, R> {
     }
 }
 
+/// A value marked to become the `Left` variant of an [`Either`].
+///
+/// A blanket `impl<L, R> From<L> for Either<L, R>` isn't possible, because it
+/// would overlap with the `From<R>` counterpart whenever `L` and `R` are the
+/// same type. Wrapping the value in `LeftOf` or [`RightOf`] picks the side
+/// explicitly, so `Either::from` and `.into()` still work generically.
+///
+/// ```
+/// use either::{Either, Left, LeftOf, Right, RightOf};
+///
+/// let left: Either<u32, u32> = LeftOf(1).into();
+/// assert_eq!(left, Left(1));
+///
+/// let right = Either::<u32, u32>::from(RightOf(2));
+/// assert_eq!(right, Right(2));
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct LeftOf<T>(pub T);
+
+/// A value marked to become the `Right` variant of an [`Either`].
+///
+/// See [`LeftOf`] for details.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct RightOf<T>(pub T);
+
+/// Convert from `LeftOf<L>` to `Left(L)`.
+impl<L, R> From<LeftOf<L>> for Either<L, R> {
+    fn from(l: LeftOf<L>) -> Self {
+        Left(l.0)
+    }
+}
+
+/// Convert from `RightOf<R>` to `Right(R)`.
+impl<L, R> From<RightOf<R>> for Either<L, R> {
+    fn from(r: RightOf<R>) -> Self {
+        Right(r.0)
+    }
+}
+
+/// Reduce a sequence of `Either` values that are all on the same side,
+/// combining `Left` values with `f` or `Right` values with `g`.
+///
+/// Returns `None` if the sequence is empty, or as soon as an item is on the
+/// other side than the first one.
+///
+/// ```
+/// use either::{Left, Right};
+///
+/// let lengths = vec![Left(1), Left(2), Left(3)];
+/// assert_eq!(either::reduce(lengths, |a, b| a + b, |a: &str, _| a), Some(Left(6)));
+///
+/// let mixed = vec![Left(1), Right("a"), Left(3)];
+/// assert_eq!(either::reduce(mixed, |a, b| a + b, |a, _| a), None);
+/// ```
+pub fn reduce<I, L, R, F, G>(items: I, mut f: F, mut g: G) -> Option<Either<L, R>>
+where
+    I: IntoIterator<Item = Either<L, R>>,
+    F: FnMut(L, L) -> L,
+    G: FnMut(R, R) -> R,
+{
+    let mut items = items.into_iter();
+    let mut acc = items.next()?;
+    for item in items {
+        acc = match (acc, item) {
+            (Left(a), Left(b)) => Left(f(a, b)),
+            (Right(a), Right(b)) => Right(g(a, b)),
+            _ => return None,
+        };
+    }
+    Some(acc)
+}
+
+/// A wrapper that orders `Right` values before `Left` values.
+///
+/// The derived ordering of [`Either`] puts every `Left` before every `Right`.
+/// `RightFirst<Either<L, R>>` reverses that priority, while values on the same
+/// side are still compared with their own ordering.
+///
+/// ```
+/// use either::{Left, Right, RightFirst};
+///
+/// let mut values = vec![
+///     RightFirst(Left(2)),
+///     RightFirst(Right(3)),
+///     RightFirst(Left(1)),
+///     RightFirst(Right(0)),
+/// ];
+/// values.sort();
+///
+/// let values: Vec<_> = values.into_iter().map(|v| v.0).collect();
+/// assert_eq!(values, vec![Right(0), Right(3), Left(1), Left(2)]);
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct RightFirst<T>(pub T);
+
+impl<L, R> PartialOrd for RightFirst<Either<L, R>>
+where
+    L: PartialOrd,
+    R: PartialOrd,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (&self.0, &other.0) {
+            (Left(a), Left(b)) => a.partial_cmp(b),
+            (Right(a), Right(b)) => a.partial_cmp(b),
+            (Left(_), Right(_)) => Some(Ordering::Greater),
+            (Right(_), Left(_)) => Some(Ordering::Less),
+        }
+    }
+}
+
+impl<L, R> Ord for RightFirst<Either<L, R>>
+where
+    L: Ord,
+    R: Ord,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (&self.0, &other.0) {
+            (Left(a), Left(b)) => a.cmp(b),
+            (Right(a), Right(b)) => a.cmp(b),
+            (Left(_), Right(_)) => Ordering::Greater,
+            (Right(_), Left(_)) => Ordering::Less,
+        }
+    }
+}
+
 /// `Either<L, R>` is a future if both `L` and `R` are futures.
+///
+/// This means an `Either` of two futures with the same output can be awaited
+/// directly, resolving to the output of whichever one it holds.
 impl<L, R> Future for Either<L, R>
 where
     L: Future,
@@ -1355,6 +3449,31 @@ where
     }
 }
 
+/// `Either<L, R>` dereferences to the common target of `L` and `R`.
+///
+/// This makes the methods of the target, like `len` and `is_empty` for
+/// slices and strings, available directly on the `Either`:
+///
+/// ```
+/// use either::*;
+///
+/// let owned: Either<Vec<u8>, &[u8]> = Left(vec![1, 2, 3]);
+/// assert_eq!(owned.len(), 3);
+///
+/// let borrowed: Either<String, &str> = Right("");
+/// assert!(borrowed.is_empty());
+/// ```
+///
+/// For types without a common target, such as two different map types, use
+/// [`for_both!`] to call the method on whichever value is present:
+///
+/// ```
+/// use either::*;
+/// use std::collections::{BTreeMap, HashMap};
+///
+/// let map: Either<HashMap<u32, u32>, BTreeMap<u32, u32>> = Right(BTreeMap::new());
+/// assert_eq!(for_both!(map, ref inner => inner.len()), 0);
+/// ```
 impl<L, R> Deref for Either<L, R>
 where
     L: Deref,
@@ -1411,6 +3530,30 @@ where
     }
 }
 
+macro_rules! impl_binary_op {
+    ($($op:ident :: $method:ident),* $(,)?) => {
+        $(
+            /// Applies the operator to the values of two `Either<T, T>`.
+            ///
+            /// The result keeps the variant of the left-hand operand, whatever
+            /// the variant of the right-hand operand is.
+            impl<T> ops::$op for Either<T, T>
+            where
+                T: ops::$op,
+            {
+                type Output = Either<T::Output, T::Output>;
+
+                fn $method(self, rhs: Self) -> Self::Output {
+                    let rhs = rhs.into_inner();
+                    map_either!(self, inner => ops::$op::$method(inner, rhs))
+                }
+            }
+        )*
+    };
+}
+
+impl_binary_op!(Add::add, Sub::sub, Mul::mul, Div::div, Rem::rem);
+
 #[test]
 fn basic() {
     let mut e = Left(2);
@@ -1424,6 +3567,28 @@ fn basic() {
     assert_eq!(e.as_mut().right(), Some(&mut 2));
 }
 
+#[test]
+fn clone_from() {
+    use std::vec::Vec;
+
+    let source: Either<Vec<u8>, Vec<u8>> = Left([1, 2, 3].to_vec());
+
+    let mut dest: Either<Vec<u8>, Vec<u8>> = Left(Vec::with_capacity(16));
+    let ptr = dest.as_ref().left().unwrap().as_ptr();
+    dest.clone_from(&source);
+    assert_eq!(dest, source);
+    // Cloning into the same side reuses the existing allocation.
+    assert_eq!(dest.as_ref().left().unwrap().as_ptr(), ptr);
+
+    let mut dest: Either<Vec<u8>, Vec<u8>> = Right(Vec::with_capacity(16));
+    dest.clone_from(&source);
+    assert_eq!(dest, source);
+
+    let source = source.flip();
+    dest.clone_from(&source);
+    assert_eq!(dest, Right([1, 2, 3].to_vec()));
+}
+
 #[test]
 fn macros() {
     use std::string::String;
@@ -1441,6 +3606,7 @@ fn macros() {
 }
 
 #[test]
+#[allow(clippy::explicit_auto_deref)]
 fn deref() {
     use std::string::String;
 
@@ -1462,6 +3628,95 @@ fn iter() {
 }
 
 #[test]
+fn iter_short_circuit() {
+    let mut iter: Either<_, core::ops::Range<u32>> = Left(0..);
+
+    assert_eq!(iter.find(|&x| x == 3), Some(3));
+    assert_eq!(iter.next(), Some(4));
+    assert!(iter.any(|x| x > 10));
+    assert_eq!(iter.position(|x| x == 14), Some(2));
+    assert_eq!(
+        iter.try_fold(0, |acc, x| if x < 17 { Some(acc + x) } else { None }),
+        None
+    );
+    assert_eq!(iter.next(), Some(18));
+}
+
+#[test]
+fn iter_by_ref() {
+    let mut iter: Either<core::ops::Range<u32>, _> = Right([1, 2, 3, 4, 5].iter().cloned());
+
+    assert_eq!(iter.by_ref().nth(1), Some(2));
+    assert_eq!(iter.by_ref().take(2).count(), 2);
+    assert_eq!(iter.next(), Some(5));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn iter_extend_reserved() {
+    use std::vec::Vec;
+
+    let data = [5, 6, 7];
+    for iter in [Left(0..4), Right(data.iter().cloned())].iter().cloned() {
+        let mut buffer: Vec<u32> = Vec::with_capacity(8);
+        buffer.push(99);
+        let capacity = buffer.capacity();
+        buffer.extend(iter.clone());
+        assert_eq!(buffer.capacity(), capacity);
+        assert_eq!(buffer[1..], *iter.collect::<Vec<_>>());
+    }
+}
+
+#[test]
+fn iter_skip() {
+    use std::vec::Vec;
+
+    let data = [0, 1, 2, 3, 4, 5, 6, 7];
+
+    let left: Either<_, core::ops::Range<u32>> = Left(data.iter().cloned());
+    let expected: Vec<_> = data.iter().cloned().skip(5).collect();
+    assert_eq!(left.skip(5).collect::<Vec<_>>(), expected);
+
+    // `Skip` reaches the range's constant-time `nth` through `Either`.
+    let right: Either<core::iter::Empty<u32>, _> = Right(0..1_000_000_000);
+    let rest: Vec<_> = right.skip(999_999_998).collect();
+    assert_eq!(rest, [999_999_998, 999_999_999]);
+}
+
+#[test]
+fn iter_either_short_circuit() {
+    let mut iter = Either::<_, core::ops::Range<i32>>::Left(0u32..).factor_into_iter();
+
+    assert_eq!(iter.find(|x| x.is_left_and(|&l| l == 3)), Some(Left(3)));
+    assert_eq!(iter.next(), Some(Left(4)));
+    assert!(iter.any(|x| x == Left(6)));
+    assert_eq!(iter.next(), Some(Left(7)));
+
+    let mut iter = Either::<core::ops::Range<u32>, _>::Right(-5..5).factor_into_iter();
+
+    assert_eq!(iter.find_map(|x| x.right().filter(|&r| r > 0)), Some(1));
+    assert!(!iter.all(|x| x != Right(3)));
+    assert_eq!(iter.next(), Some(Right(4)));
+}
+
+#[test]
+fn iter_either_rev_nth() {
+    let data: [u8; 5] = [0, 1, 2, 3, 4];
+
+    let left: Either<_, &[u8]> = Left(&data[..]);
+    let mut iter = left.factor_into_iter().rev();
+    assert_eq!(iter.nth(1), Some(Left(&3)));
+    assert_eq!(iter.nth(2), Some(Left(&0)));
+    assert_eq!(iter.next(), None);
+
+    let right: Either<&[u8], _> = Right(&data[..]);
+    let mut iter = right.factor_into_iter().rev();
+    assert_eq!(iter.nth(4), Some(Right(&0)));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+#[allow(clippy::needless_range_loop)]
 fn seek() {
     use std::io;
 
@@ -1520,6 +3775,61 @@ fn read_write() {
     assert_eq!(writer.write(&buf).unwrap(), buf.len());
 }
 
+#[test]
+fn buf_read_lines() {
+    use std::io::{BufReader, Cursor};
+    use std::string::String;
+    use std::vec::Vec;
+
+    let text = "first\nsecond\r\n\nlast";
+    let expected: Vec<String> = text.as_bytes().lines().map(Result::unwrap).collect();
+
+    let mut left: Either<_, Cursor<Vec<u8>>> = Left(BufReader::new(text.as_bytes()));
+    let mut line = String::new();
+    assert_eq!(left.read_line(&mut line).unwrap(), 6);
+    assert_eq!(line, "first\n");
+    let rest: Vec<String> = left.lines().map(Result::unwrap).collect();
+    assert_eq!(rest, &expected[1..]);
+
+    let mut right: Either<BufReader<&[u8]>, _> = Right(Cursor::new(text.as_bytes().to_vec()));
+    let mut until = Vec::new();
+    assert_eq!(right.read_until(b'\r', &mut until).unwrap(), 13);
+    assert_eq!(until, b"first\nsecond\r");
+    let rest: Vec<String> = right.lines().map(Result::unwrap).collect();
+    assert_eq!(rest, ["", "", "last"]);
+
+    let right: Either<BufReader<&[u8]>, _> = Right(Cursor::new(text.as_bytes().to_vec()));
+    let all: Vec<String> = right.lines().map(Result::unwrap).collect();
+    assert_eq!(all, expected);
+}
+
+#[test]
+fn write_all_flush() {
+    use std::io::BufWriter;
+    use std::vec::Vec;
+
+    let data: Vec<u8> = (0..100_000u32).map(|i| i as u8).collect();
+
+    let mut direct = BufWriter::new(Vec::new());
+    direct.write_all(&data).unwrap();
+    write!(direct, "{}-{}", 1, 2).unwrap();
+    direct.flush().unwrap();
+
+    let mut left: Either<_, Vec<u8>> = Left(BufWriter::new(Vec::new()));
+    left.write_all(&data).unwrap();
+    write!(left, "{}-{}", 1, 2).unwrap();
+    left.flush().unwrap();
+
+    let mut right: Either<BufWriter<Vec<u8>>, _> = Right(Vec::new());
+    right.write_all(&data).unwrap();
+    write!(right, "{}-{}", 1, 2).unwrap();
+    right.flush().unwrap();
+
+    let direct = direct.into_inner().unwrap();
+    assert_eq!(left.left().unwrap().into_inner().unwrap(), direct);
+    assert_eq!(right.right().unwrap(), direct);
+}
+
 #[test]
 fn error() {
     let invalid_utf8 = b"\xff";
@@ -1536,6 +3846,21 @@ fn error() {
     res.unwrap_err().description(); // make sure this can be called
 }
 
+#[test]
+fn arithmetic() {
+    let left: Either<i32, i32> = Left(7);
+    let right: Either<i32, i32> = Right(2);
+
+    assert_eq!(left + right, Left(9));
+    assert_eq!(right + left, Right(9));
+    assert_eq!(left - right, Left(5));
+    assert_eq!(right - right, Right(0));
+    assert_eq!(left * left, Left(49));
+    assert_eq!(left / right, Left(3));
+    assert_eq!(left % right, Left(1));
+    assert_eq!(right % left, Right(2));
+}
+
 /// A helper macro to check if AsRef and AsMut are implemented for a given type.
 macro_rules! check_t {
     ($t:ty) => {{
@@ -1573,3 +3898,23 @@ fn _unsized_std_propagation() {
     check_t!(::std::ffi::OsStr);
     check_t!(::std::ffi::CStr);
 }
+
+// These "unused" methods are here to ensure that `Either` and `IterEither` stay
+// covariant in both type parameters, so lifetimes can be shortened implicitly.
+fn _covariant_either<'a: 'b, 'b, L, R>(value: Either<&'a L, &'a R>) -> Either<&'b L, &'b R> {
+    value
+}
+
+fn _covariant_iter_either<'a: 'b, 'b, L, R>(
+    iter: IterEither<&'a L, &'a R>,
+) -> IterEither<&'b L, &'b R> {
+    iter
+}
+
+// This "unused" method is here to ensure that `Either` and `IterEither` stay
+// `Send` and `Sync` when both sides are.
+fn _send_sync<L: Send + Sync, R: Send + Sync>() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<Either<L, R>>();
+    assert_send_sync::<IterEither<L, R>>();
+}
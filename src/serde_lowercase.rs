@@ -0,0 +1,70 @@
+//! Lowercase-tagged serialization/deserialization support for Either<L, R>.
+//!
+//! `Either` uses default, externally-tagged representation, with the variant
+//! names `"Left"` and `"Right"` as tags.
+//! However, many formats and APIs expect lowercase names instead.
+//! This module keeps the externally-tagged representation, but uses the
+//! tags `"left"` and `"right"`.
+//!
+//! ```rust
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use either::Either;
+//!
+//! #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+//! struct Record {
+//!     #[serde(with = "either::serde_lowercase")]
+//!     value: Either<u32, String>,
+//! };
+//!
+//! // serialization
+//! let data = Record {
+//!     value: Either::Left(3),
+//! };
+//! let json = serde_json::to_string(&data)?;
+//! assert_eq!(json, r#"{"value":{"left":3}}"#);
+//!
+//! // deserialization
+//! assert_eq!(serde_json::from_str::<Record>(&json)?, data);
+//! let data: Record = serde_json::from_str(r#"{"value":{"right":"text"}}"#)?;
+//! assert_eq!(data.value, Either::Right("text".to_string()));
+//!
+//! // the default tags are not accepted
+//! assert!(serde_json::from_str::<Record>(r#"{"value":{"Left":3}}"#).is_err());
+//! # Ok(())
+//! # }
+//! ```
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum Either<L, R> {
+    Left(L),
+    Right(R),
+}
+
+pub fn serialize<L, R, S>(this: &super::Either<L, R>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    L: Serialize,
+    R: Serialize,
+{
+    let lowercase = match this {
+        super::Either::Left(left) => Either::Left(left),
+        super::Either::Right(right) => Either::Right(right),
+    };
+    lowercase.serialize(serializer)
+}
+
+pub fn deserialize<'de, L, R, D>(deserializer: D) -> Result<super::Either<L, R>, D::Error>
+where
+    D: Deserializer<'de>,
+    L: Deserialize<'de>,
+    R: Deserialize<'de>,
+{
+    match Either::deserialize(deserializer) {
+        Ok(Either::Left(left)) => Ok(super::Either::Left(left)),
+        Ok(Either::Right(right)) => Ok(super::Either::Right(right)),
+        Err(error) => Err(error),
+    }
+}
@@ -0,0 +1,72 @@
+//! Lowercase-tagged serialization/deserialization support for Option<Either<L, R>>.
+//!
+//! `Either` uses default, externally-tagged representation, with the variant
+//! names `"Left"` and `"Right"` as tags.
+//! However, many formats and APIs expect lowercase names instead.
+//! This module keeps the externally-tagged representation, but uses the
+//! tags `"left"` and `"right"`.
+//!
+//! ```rust
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use either::Either;
+//!
+//! #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+//! struct Record {
+//!     #[serde(with = "either::serde_lowercase_optional")]
+//!     value: Option<Either<u32, String>>,
+//! };
+//!
+//! // serialization
+//! let data = Record {
+//!     value: Some(Either::Right("text".to_string())),
+//! };
+//! let json = serde_json::to_string(&data)?;
+//! assert_eq!(json, r#"{"value":{"right":"text"}}"#);
+//!
+//! // deserialization
+//! assert_eq!(serde_json::from_str::<Record>(&json)?, data);
+//! let data: Record = serde_json::from_str(r#"{"value":null}"#)?;
+//! assert_eq!(data.value, None);
+//! # Ok(())
+//! # }
+//! ```
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum Either<L, R> {
+    Left(L),
+    Right(R),
+}
+
+pub fn serialize<L, R, S>(
+    this: &Option<super::Either<L, R>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    L: Serialize,
+    R: Serialize,
+{
+    let lowercase = match this {
+        Some(super::Either::Left(left)) => Some(Either::Left(left)),
+        Some(super::Either::Right(right)) => Some(Either::Right(right)),
+        None => None,
+    };
+    lowercase.serialize(serializer)
+}
+
+pub fn deserialize<'de, L, R, D>(deserializer: D) -> Result<Option<super::Either<L, R>>, D::Error>
+where
+    D: Deserializer<'de>,
+    L: Deserialize<'de>,
+    R: Deserialize<'de>,
+{
+    match Option::deserialize(deserializer) {
+        Ok(Some(Either::Left(left))) => Ok(Some(super::Either::Left(left))),
+        Ok(Some(Either::Right(right))) => Ok(Some(super::Either::Right(right))),
+        Ok(None) => Ok(None),
+        Err(error) => Err(error),
+    }
+}
@@ -5,6 +5,11 @@
 //! For example, we may have a field which is generally Map<String, i32>
 //! but in typical cases Vec<String> would suffice, too.
 //!
+//! When deserializing, the input is tried as `L` first, and only as `R` if that
+//! fails, so `L` takes precedence when the input would match both. If it matches
+//! neither, deserialization fails with a generic error that doesn't include the
+//! errors from either side.
+//!
 //! ```rust
 //! # fn main() -> Result<(), Box<dyn std::error::Error>> {
 //! use either::Either;
@@ -29,6 +34,9 @@
 //!     r#"{"a": 0, "b": 14}"#
 //! )?;
 //! println!("found {:?}", data);
+//!
+//! // neither a list of strings nor a map of integers
+//! assert!(serde_json::from_str::<IntOrString>(r#"{"a": "b"}"#).is_err());
 //! # Ok(())
 //! # }
 //! ```